@@ -1,7 +1,6 @@
-use crate::utils::tools::run_command;
 use clap::Parser;
-use remove_dir_all::*;
-use tokio;
+use remove_dir_all::remove_dir_all;
+use std::path::Path;
 use tokio::fs;
 use utils::prompts::Prompts;
 use utils::{args, file, log, pkg, prompts, render};
@@ -14,15 +13,94 @@ async fn main() {
     println!("\n{}", log::blue(welcome.as_str()));
 
     let args = args::Args::parse();
-    let options = prompts::get_prompts(args);
-    create(options).await;
+    match args.command {
+        Some(args::Commands::Generate { target }) => generate(target),
+        None if args.monorepo => create_monorepo(args).await,
+        None => {
+            let options = prompts::get_prompts(args);
+            let success = create(&options).await;
+            print_success(&options, success);
+        }
+    }
 }
 
-async fn create(options: Prompts) {
-    let root = options.root.as_path();
-    if options.overwrite {
+fn generate(target: args::GenerateTarget) {
+    let result = match target {
+        args::GenerateTarget::Page {
+            path,
+            title,
+            parent,
+        } => utils::generate::page::create(&path, &title, parent.as_deref()),
+        args::GenerateTarget::Api { spec, name } => {
+            utils::generate::api::create(&spec, name.as_deref())
+        }
+    };
+
+    if let Err(e) = result {
+        log::error(format!("生成失败: {}", e));
+        std::process::exit(1);
+    }
+}
+
+async fn create_monorepo(args: args::Args) {
+    let monorepo = prompts::get_monorepo_prompts(&args);
+    let root = monorepo.root.as_path();
+    // 之后会切换到monorepo根目录，提前生成进入目录的提示
+    let cd_command = get_cd_command(root);
+    prepare_dir(root, &monorepo.existing).await;
+    if monorepo.existing == "merge" {
+        let staging = file::get_staging_dir();
+        let _ = fs::create_dir_all(&staging).await;
+        render::monorepo::create(&prompts::MonorepoPrompts {
+            root: staging.clone(),
+            ..monorepo.clone()
+        });
+        merge_dir(&staging, root);
+    } else {
+        render::monorepo::create(&monorepo);
+    }
+    // 应用安装依赖时会执行根目录的prepare脚本，需要先初始化git仓库
+    let git = monorepo
+        .git
+        .as_ref()
+        .filter(|git| utils::git::init(root, git));
+
+    // 在monorepo根目录下按普通流程依次创建应用，会被识别为pnpm工作区并创建到apps目录下
+    std::env::set_current_dir(root).unwrap();
+    let mut success = true;
+    for app in &monorepo.apps {
+        log::info(format!("正在创建应用{}", app));
+        let app_args = args::Args {
+            project: None,
+            project_name: Some(app.clone()),
+            force: None,
+            monorepo: false,
+            ..args.clone()
+        };
+        let options = prompts::get_prompts(app_args);
+        success &= create(&options).await;
+        render::monorepo::link_app(&monorepo, &options);
+    }
+
+    if let Some(git) = git {
+        utils::git::commit(root, git);
+    }
+
+    println!(
+        "{}\n\n{}\t👉 {}\n\t👉 {}\n",
+        get_success_title(&monorepo.project_name, success),
+        cd_command,
+        pkg::get_install_command("pnpm"),
+        pkg::get_run_command("pnpm", "dev"),
+    );
+}
+
+/// 清除已有文件并创建项目目录
+async fn prepare_dir(root: &Path, existing: &str) {
+    if existing == "remove" {
         log::info(format!("正在清除{:?}目录", root));
-        match remove_dir_all(root) {
+        // 只清除目录中的文件，在当前目录创建时保留当前目录及.git
+        match file::empty_dir(root) {
             Ok(_) => {}
             Err(e) => log::error(format!("清除目录出错: {}", e)),
         };
@@ -30,24 +108,201 @@ async fn create(options: Prompts) {
 
     log::info(format!("正在创建{:?}目录", root));
     if !file::file_exists(root) {
-        let _ = fs::create_dir(root).await;
+        let _ = fs::create_dir_all(root).await;
     }
+}
 
-    // 渲染package.json
-    render::package::create(&options).await;
-    // 模板目录名
+/// 将临时目录中生成的文件合并到项目目录，并输出冲突文件的处理结果
+fn merge_dir(staging: &Path, root: &Path) {
+    match file::merge_dir(staging, root, prompts::get_conflict_value) {
+        Ok(report) => {
+            for file in &report.overwritten {
+                log::info(format!("已覆盖：{:?}", file));
+            }
+            for file in &report.skipped {
+                log::info(format!("已保留：{:?}", file));
+            }
+            log::info(format!(
+                "合并完成：新增{}个文件，{}个文件内容相同，覆盖{}个冲突文件，保留{}个冲突文件",
+                report.created.len(),
+                report.unchanged.len(),
+                report.overwritten.len(),
+                report.skipped.len()
+            ));
+        }
+        Err(e) => log::error(format!("合并文件出错: {}", e)),
+    }
+
+    if let Err(e) = remove_dir_all(staging) {
+        log::error(format!("清除临时目录出错: {}", e));
+    }
+}
+
+/// 创建项目，返回创建后的命令是否全部执行成功
+async fn create(options: &Prompts) -> bool {
+    let root = options.root.as_path();
+    if let Some(workspace) = &options.workspace {
+        log::info(format!(
+            "检测到{}工作区{:?}",
+            workspace.package_manager, workspace.root
+        ));
+    }
+
+    prepare_dir(root, &options.existing).await;
+    // 合并到已有目录时先生成到临时目录，再逐个文件合并
+    if options.existing == "merge" {
+        let staging = file::get_staging_dir();
+        let _ = fs::create_dir_all(&staging).await;
+        render_project(&Prompts {
+            root: staging.clone(),
+            ..options.clone()
+        })
+        .await;
+        merge_dir(&staging, root);
+    } else {
+        render_project(options).await;
+    }
+
+    // 先初始化git仓库，安装依赖时prepare脚本才能安装git钩子
+    let git = match &options.workspace {
+        Some(workspace) => {
+            match workspace.register(root) {
+                Ok(true) => log::info("已将项目添加到工作区配置中".to_string()),
+                Ok(false) => {}
+                Err(e) => log::error(format!("添加到工作区配置出错: {}", e)),
+            }
+            None
+        }
+        None => options
+            .git
+            .as_ref()
+            .filter(|git| utils::git::init(root, git)),
+    };
+
+    let success = !options.post_create
+        || match render::template::get_hooks(get_template_dirs(options)) {
+            Ok(mut hooks) => {
+                hooks.extend(options.hooks.iter().cloned());
+                utils::hooks::run(&hooks, root, &options.package_manager)
+            }
+            Err(e) => {
+                log::error(format!("读取模板清单出错: {}", e));
+                false
+            }
+        };
+
+    // 初始提交包含锁文件及格式化后的代码
+    if let Some(git) = git {
+        utils::git::commit(root, git);
+    }
+
+    success
+}
+
+/// 按顺序复制的模板目录名
+fn get_template_dirs(options: &Prompts) -> Vec<&str> {
     let mut template_dirs = vec!["base", options.template.as_str()];
-    if !options.js_lint.is_empty() {
+    // 工作区根目录已有格式化配置时沿用根目录的配置
+    let workspace = options.workspace.as_ref();
+    if !options.js_lint.is_empty()
+        && !workspace.is_some_and(|w| w.has_js_lint_config(&options.js_lint))
+    {
         template_dirs.push(options.js_lint.as_str());
     }
 
-    if options.style_lint {
+    if options.style_lint && !workspace.is_some_and(|w| w.has_style_lint_config()) {
         template_dirs.push("stylelint");
     }
+
+    if !options.test.is_empty() {
+        template_dirs.push(options.test.as_str());
+    }
+
+    if options.e2e {
+        template_dirs.push("playwright");
+    }
+
+    if options.react_query {
+        template_dirs.push("react_query");
+    }
+
+    if options.dark_mode {
+        template_dirs.push("dark_mode");
+    }
+
+    match options.federation.as_str() {
+        "host" => template_dirs.push("federation_host"),
+        "remote" => template_dirs.push("federation_remote"),
+        _ => {}
+    }
+
+    if options.rbac {
+        template_dirs.push("rbac");
+    }
+
+    if options.mock {
+        template_dirs.push("mock");
+    }
+    template_dirs
+}
+
+/// 在options.root中生成项目文件
+async fn render_project(options: &Prompts) {
+    // 渲染package.json
+    render::package::create(options).await;
     // 渲染模板
-    render::template::copy(template_dirs, &options.root).unwrap();
-    render::config::rzpack_config(&options);
-    render::config::gitignore(&options.root);
+    if let Err(e) = render::template::copy(
+        get_template_dirs(options),
+        &[
+            ("layout", options.layout.as_str()),
+            ("state", options.state.as_str()),
+            ("http", options.http.as_str()),
+            ("router", options.router_mode.as_str()),
+        ],
+        &options.root,
+    ) {
+        log::error(format!("复制模板出错: {}", e));
+        std::process::exit(1);
+    }
+    if options.rbac {
+        if let Err(e) = render::rbac::create(options) {
+            log::error(format!("添加权限控制出错: {}", e));
+            std::process::exit(1);
+        }
+        // 角色管理页面只有拥有system:role权限的用户可以访问
+        utils::generate::page::register(
+            &options.root,
+            "System/Role",
+            "角色管理",
+            Some("/system"),
+            &["system:role"],
+        )
+        .unwrap();
+    }
+    if options.federation == "host" {
+        // 示例子应用页面，见src/pages/Micro/SubApp
+        utils::generate::page::register(
+            &options.root,
+            "Micro/SubApp",
+            "子应用",
+            Some("/workbench"),
+            &[],
+        )
+        .unwrap();
+    }
+    if options.template == "admin" {
+        render::config::app_entry(options);
+        render::config::app_title(options);
+        if options.react_query {
+            render::config::react_query(options);
+        }
+    }
+    if options.template != "react_ts" {
+        render::config::theme(options);
+    }
+    render::config::rzpack_config(options);
+    render::config::gitignore(options);
+    render::config::env(options);
     if options.commit_lint {
         render::config::commit_lint_config(&options.root);
     }
@@ -55,18 +310,76 @@ async fn create(options: Prompts) {
     if options.rs {
         render::config::nodemon(&options.root);
     }
-    render::config::readme(&options);
-    log::info("正在初始化git仓库...".to_string());
-    match run_command("git", &["init"]) {
-        Ok(_) => {}
-        Err(_) => {
-            log::error("初始化git仓库失败".to_string());
-        }
-    };
 
-    let project_name = log::bold(options.project_name.as_str());
+    if !options.test.is_empty() {
+        render::config::app_test(options);
+    }
+
+    if options.e2e {
+        render::config::playwright(options);
+    }
+
+    render::config::ci(options);
+    if options.docker {
+        render::deploy::create(options);
+    }
+    render::config::readme(options);
+}
+
+/// 创建后的命令执行失败时提示手动处理
+fn get_success_title(project_name: &str, success: bool) -> String {
+    let project_name = log::bold(project_name);
+    if success {
+        format!("✨  项目{}创建成功!!! 🚀🚀🚀", project_name)
+    } else {
+        format!(
+            "⚠️  项目{}已创建，但部分初始化命令执行失败，请根据上面的输出手动执行",
+            project_name
+        )
+    }
+}
+
+fn print_success(options: &Prompts, success: bool) {
     println!(
-        "✨  项目{}创建成功!!! 🚀🚀🚀\n\n\t👉 cd {}\n\t👉 npm install\n\t👉 npm run dev\n",
-        project_name, project_name,
+        "{}\n\n{}\t👉 {}\n\t👉 {}\n",
+        get_success_title(&options.project_name, success),
+        get_cd_command(&options.root),
+        pkg::get_install_command(&options.package_manager),
+        pkg::get_run_command(&options.package_manager, "dev"),
     );
 }
+
+/// 进入项目目录的提示，在当前目录创建时不需要，不在当前目录下时使用绝对路径
+fn get_cd_command(root: &Path) -> String {
+    let current_dir = file::get_current_dir();
+    if root == current_dir {
+        return String::new();
+    }
+
+    let project_dir = root.strip_prefix(&current_dir).unwrap_or(root);
+    format!(
+        "\t👉 cd {}\n",
+        log::bold(quote_path(project_dir.to_string_lossy().as_ref()).as_str())
+    )
+}
+
+/// 路径中包含空白字符时需要加上引号，才能直接复制到终端中执行
+fn quote_path(path: &str) -> String {
+    if path.contains(char::is_whitespace) {
+        format!("\"{}\"", path)
+    } else {
+        path.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quote_path_with_whitespace() {
+        assert_eq!(quote_path("my-app"), "my-app");
+        assert_eq!(quote_path("apps/my app"), "\"apps/my app\"");
+        assert_eq!(quote_path("/tmp/a\tb"), "\"/tmp/a\tb\"");
+    }
+}
@@ -1,15 +1,80 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
-#[derive(Parser, Debug)]
-#[command(author, version, about)]
+#[derive(Parser, Debug, Clone)]
+#[command(author, version, about, args_conflicts_with_subcommands = true)]
 pub struct Args {
-    /// 创建项目模板名称
-    #[arg(short, long, value_name = "String", default_value_t = String::from("rzpack-app"))]
-    pub project_name: String,
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+    /// 项目目录，支持嵌套路径(如apps/admin)，.表示当前目录
+    #[arg(value_name = "PROJECT")]
+    pub project: Option<String>,
+    /// 项目目录，同PROJECT
+    #[arg(short, long, value_name = "String", conflicts_with = "project")]
+    pub project_name: Option<String>,
     /// 模板类型
     #[arg(value_enum, short, long)]
     pub template: Option<String>,
-    /// 是否覆盖目录
+    /// admin模板的布局(side/header/mixed)
+    #[arg(long, value_name = "String")]
+    pub layout: Option<String>,
+    /// 目标目录不为空时直接删除已有文件(保留.git)，不再询问
     #[arg(short, long, value_name = None)]
     pub force: Option<bool>,
+    /// 测试框架(vitest/jest)
+    #[arg(long, value_name = "String")]
+    pub test: Option<String>,
+    /// 创建包含多个应用和共享包的pnpm monorepo
+    #[arg(long)]
+    pub monorepo: bool,
+    /// 不初始化git仓库
+    #[arg(long)]
+    pub no_git: bool,
+    /// git仓库的默认分支
+    #[arg(long, value_name = "String", default_value_t = String::from("main"))]
+    pub branch: String,
+    /// 初始化git仓库后不创建初始提交
+    #[arg(long)]
+    pub no_commit: bool,
+    /// 初始提交的提交信息
+    #[arg(long, value_name = "String", default_value_t = String::from("chore: init project"))]
+    pub commit_message: String,
+    /// git远程仓库地址，会被添加为origin
+    #[arg(long, value_name = "String")]
+    pub remote: Option<String>,
+    /// 用户预设文件(TOML)，其中的[[hooks]]会在项目创建完成后执行
+    #[arg(long, value_name = "Path")]
+    pub preset: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Commands {
+    /// 在admin模板创建的项目中生成代码，需要在项目根目录下执行
+    Generate {
+        #[command(subcommand)]
+        target: GenerateTarget,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum GenerateTarget {
+    /// 生成页面组件并在src/router/routes.ts中注册路由
+    Page {
+        /// 页面组件路径(相对于src/pages)，如System/Role
+        path: String,
+        /// 页面标题
+        #[arg(long, value_name = "String")]
+        title: String,
+        /// 父级路由地址，如/system，不传则注册为顶级路由
+        #[arg(long, value_name = "String")]
+        parent: Option<String>,
+    },
+    /// 根据OpenAPI 3文档(JSON/YAML)生成src/model和src/api下的类型及请求函数
+    Api {
+        /// OpenAPI文档的路径
+        spec: String,
+        /// 生成的文件名，默认使用文档的文件名，需要以字母开头且只包含字母和数字
+        #[arg(long, value_name = "String")]
+        name: Option<String>,
+    },
 }
@@ -1,12 +1,108 @@
+use remove_dir_all::remove_dir_all;
 use std::env;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::process;
+use walkdir::WalkDir;
 
-pub fn can_overwrite_directory(path: &str) -> bool {
-    let is_exist = fs::metadata(path).is_ok();
-    let has_auth = fs::read_dir(path).is_ok();
+/// git仓库目录，判断目录是否为空及清除目录时忽略
+const GIT_DIR: &str = ".git";
 
-    is_exist || has_auth
+/// 合并目录的结果，路径相对于目标目录
+#[derive(Debug, Default)]
+pub struct MergeReport {
+    /// 新增的文件
+    pub created: Vec<PathBuf>,
+    /// 内容相同未做修改的文件
+    pub unchanged: Vec<PathBuf>,
+    /// 冲突后被覆盖的文件
+    pub overwritten: Vec<PathBuf>,
+    /// 冲突后保留已有内容的文件
+    pub skipped: Vec<PathBuf>,
+}
+
+/// 目录不存在或只有.git时视为空目录
+pub fn is_empty_dir(path: &Path) -> bool {
+    match fs::read_dir(path) {
+        Ok(entries) => entries.flatten().all(|entry| entry.file_name() == GIT_DIR),
+        Err(_) => !path.exists(),
+    }
+}
+
+/// 清除目录中除.git以外的文件
+pub fn empty_dir(path: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.file_name() == GIT_DIR {
+            continue;
+        }
+
+        if entry.file_type()?.is_dir() {
+            remove_dir_all(entry.path())?;
+        } else {
+            fs::remove_file(entry.path())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// 合并时生成文件的临时目录
+pub fn get_staging_dir() -> PathBuf {
+    env::temp_dir().join(format!("create-rzpack-{}", process::id()))
+}
+
+/// 将src中的文件合并到dest中，与已有文件内容不同时由resolve决定处理方式，
+/// 返回skip/overwrite/skip_all/overwrite_all
+pub fn merge_dir(
+    src: &Path,
+    dest: &Path,
+    mut resolve: impl FnMut(&Path) -> &'static str,
+) -> io::Result<MergeReport> {
+    let mut report = MergeReport::default();
+    // 选择全部覆盖/全部保留后不再询问
+    let mut conflict_all: Option<&str> = None;
+
+    for entry in WalkDir::new(src).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry.path().strip_prefix(src).unwrap().to_path_buf();
+        let target = dest.join(&relative);
+        let content = fs::read(entry.path())?;
+        if target.exists() {
+            if fs::read(&target).is_ok_and(|existing| existing == content) {
+                report.unchanged.push(relative);
+                continue;
+            }
+
+            let action = match conflict_all {
+                Some(action) => action,
+                None => match resolve(&relative) {
+                    "skip_all" => *conflict_all.insert("skip"),
+                    "overwrite_all" => *conflict_all.insert("overwrite"),
+                    action => action,
+                },
+            };
+            if action == "skip" {
+                report.skipped.push(relative);
+                continue;
+            }
+            fs::write(&target, content)?;
+            report.overwritten.push(relative);
+        } else {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&target, content)?;
+            report.created.push(relative);
+        }
+    }
+
+    Ok(report)
 }
 
 pub fn get_current_dir() -> PathBuf {
@@ -16,12 +112,32 @@ pub fn get_current_dir() -> PathBuf {
     }
 }
 
+/// 解析相对路径，去掉其中的.和..，如`resolve_path("/a", "./b/../c")`为`/a/c`
 pub fn resolve_path(base: &Path, relative: &Path) -> PathBuf {
-    if relative.is_absolute() {
+    let path = if relative.is_absolute() {
         relative.to_path_buf()
     } else {
         base.join(relative)
+    };
+
+    let mut resolved = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            _ => resolved.push(component),
+        }
     }
+    resolved
+}
+
+/// 目录名，作为项目名称使用
+pub fn get_dir_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
 }
 
 pub fn file_exists(path: &Path) -> bool {
@@ -30,3 +146,141 @@ pub fn file_exists(path: &Path) -> bool {
         Err(_) => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::pkg;
+
+    fn create_temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("create-rzpack-{}-{}", name, process::id()));
+        let _ = remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_file(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn empty_dir_ignores_git() {
+        let dir = create_temp_dir("is-empty");
+        assert!(is_empty_dir(&dir.join("missing")));
+        assert!(is_empty_dir(&dir));
+
+        write_file(&dir.join(".git/HEAD"), "ref: refs/heads/main");
+        assert!(is_empty_dir(&dir));
+
+        write_file(&dir.join("README.md"), "");
+        assert!(!is_empty_dir(&dir));
+        // 已存在的文件不是空目录
+        assert!(!is_empty_dir(&dir.join("README.md")));
+
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn empty_dir_keeps_git() {
+        let dir = create_temp_dir("empty");
+        write_file(&dir.join(".git/HEAD"), "ref: refs/heads/main");
+        write_file(&dir.join("src/index.ts"), "");
+        write_file(&dir.join("package.json"), "{}");
+
+        empty_dir(&dir).unwrap();
+        assert!(dir.join(".git/HEAD").exists());
+        assert!(!dir.join("src").exists());
+        assert!(!dir.join("package.json").exists());
+        assert!(is_empty_dir(&dir));
+
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn merge_into_existing_dir() {
+        let dir = create_temp_dir("merge");
+        let (src, dest) = (dir.join("src"), dir.join("dest"));
+        write_file(&src.join("a.txt"), "a");
+        write_file(&src.join("b.txt"), "new b");
+        write_file(&src.join("c.txt"), "new c");
+        write_file(&src.join("d.txt"), "new d");
+        write_file(&src.join("src/index.ts"), "index");
+        write_file(&dest.join("a.txt"), "a");
+        write_file(&dest.join("b.txt"), "b");
+        write_file(&dest.join("c.txt"), "c");
+        write_file(&dest.join("d.txt"), "d");
+
+        let mut asked = vec![];
+        let report = merge_dir(&src, &dest, |file| {
+            asked.push(file.to_path_buf());
+            match asked.len() {
+                1 => "overwrite",
+                _ => "skip_all",
+            }
+        })
+        .unwrap();
+
+        // 选择全部保留后不再询问
+        assert_eq!(asked, vec![PathBuf::from("b.txt"), PathBuf::from("c.txt")]);
+        assert_eq!(report.created, vec![PathBuf::from("src/index.ts")]);
+        assert_eq!(report.unchanged, vec![PathBuf::from("a.txt")]);
+        assert_eq!(report.overwritten, vec![PathBuf::from("b.txt")]);
+        assert_eq!(
+            report.skipped,
+            vec![PathBuf::from("c.txt"), PathBuf::from("d.txt")]
+        );
+        assert_eq!(fs::read_to_string(dest.join("b.txt")).unwrap(), "new b");
+        assert_eq!(fs::read_to_string(dest.join("c.txt")).unwrap(), "c");
+        assert_eq!(fs::read_to_string(dest.join("d.txt")).unwrap(), "d");
+        assert_eq!(
+            fs::read_to_string(dest.join("src/index.ts")).unwrap(),
+            "index"
+        );
+
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn resolve_project_path() {
+        let base = env::temp_dir().join("workspace");
+
+        assert_eq!(resolve_path(&base, Path::new(".")), base);
+        assert_eq!(
+            resolve_path(&base, Path::new("a/b/c")),
+            base.join("a").join("b").join("c")
+        );
+        assert_eq!(
+            resolve_path(&base, Path::new("apps/admin/")),
+            base.join("apps").join("admin")
+        );
+        assert_eq!(
+            resolve_path(&base, Path::new("./apps/../web")),
+            base.join("web")
+        );
+
+        let absolute = env::temp_dir().join("other").join("app");
+        assert_eq!(resolve_path(&base, &absolute), absolute);
+        assert_eq!(
+            resolve_path(&base, &absolute.join("..").join(".")),
+            env::temp_dir().join("other")
+        );
+    }
+
+    #[test]
+    fn dir_name_as_package_name() {
+        let base = env::temp_dir().join("workspace");
+
+        let name = get_dir_name(&resolve_path(&base, Path::new("apps/admin-web/")));
+        assert_eq!(name, "admin-web");
+        assert!(pkg::is_valid_package_name(&name));
+
+        let name = get_dir_name(&resolve_path(&base, Path::new(".")));
+        assert_eq!(name, "workspace");
+        assert!(pkg::is_valid_package_name(&name));
+
+        // 不符合npm规范的目录名会在创建时重新询问Package name
+        let name = get_dir_name(&resolve_path(&base, Path::new("apps/Admin Web")));
+        assert!(!pkg::is_valid_package_name(&name));
+    }
+}
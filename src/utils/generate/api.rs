@@ -0,0 +1,797 @@
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashSet};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use crate::utils::{file, log};
+
+const METHODS: [&str; 5] = ["get", "post", "put", "patch", "delete"];
+
+pub fn create(spec: &str, name: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let root = file::get_current_dir();
+    if !root.join("src/api/request.ts").is_file() {
+        return Err("未找到src/api/request.ts，请在admin模板创建的项目根目录下执行".into());
+    }
+
+    let spec_path = file::resolve_path(root.as_path(), Path::new(spec));
+    let doc = read_spec(&spec_path)?;
+    if !doc["openapi"].as_str().is_some_and(|v| v.starts_with('3')) {
+        return Err(format!("{}不是OpenAPI 3的文档", spec).into());
+    }
+
+    let name = match name {
+        Some(name) => name.to_string(),
+        None => spec_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or("无法从文档文件名获取模块名，请使用--name指定")?
+            .to_string(),
+    };
+    if !is_valid_name(&name) {
+        return Err(format!("模块名{}错误，需要以字母开头且只包含字母和数字", name).into());
+    }
+
+    let model_path = root.join("src/model").join(format!("{}.ts", name));
+    let api_path = root.join("src/api").join(format!("{}.ts", name));
+    for path in [&model_path, &api_path] {
+        if path.exists() {
+            return Err(format!("文件{:?}已存在", path).into());
+        }
+    }
+
+    let generator = Generator { doc: &doc };
+    let model = generator.render_models();
+    let api = generator.render_api(&name)?;
+
+    fs::create_dir_all(root.join("src/model"))?;
+    fs::write(&model_path, model)?;
+    log::info(format!("创建文件成功：{:?}", model_path));
+    fs::write(&api_path, api)?;
+    log::info(format!("创建文件成功：{:?}", api_path));
+
+    Ok(())
+}
+
+fn read_spec(path: &Path) -> Result<Value, Box<dyn Error>> {
+    let content = fs::read_to_string(path).map_err(|e| format!("读取文档{:?}失败: {}", path, e))?;
+    let is_yaml = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext == "yaml" || ext == "yml");
+
+    let doc = if is_yaml {
+        serde_yaml::from_str(&content)?
+    } else {
+        serde_json::from_str(&content)?
+    };
+
+    Ok(doc)
+}
+
+struct Generator<'a> {
+    doc: &'a Value,
+}
+
+impl Generator<'_> {
+    fn schemas(&self) -> Option<&Map<String, Value>> {
+        self.doc["components"]["schemas"].as_object()
+    }
+
+    /// 解析`#/components/...`的引用
+    fn resolve<'b>(&'b self, schema: &'b Value) -> &'b Value {
+        match schema["$ref"].as_str() {
+            Some(reference) => reference
+                .strip_prefix("#/")
+                .map(|pointer| format!("/{}", pointer))
+                .and_then(|pointer| self.doc.pointer(&pointer))
+                .unwrap_or(schema),
+            None => schema,
+        }
+    }
+
+    fn render_models(&self) -> String {
+        let mut content = String::from("// 由create-rzpack根据OpenAPI文档生成\n");
+
+        if let Some(schemas) = self.schemas() {
+            for (name, schema) in schemas {
+                content += comment_line(schema, "").as_str();
+                if is_object_schema(schema) {
+                    content += format!("export interface {} ", type_name(name)).as_str();
+                    content += self.render_object(schema, "").as_str();
+                    content += "\n";
+                } else {
+                    let ts_type = self.render_type(schema, "");
+                    content += format!("export type {} = {}\n", type_name(name), ts_type).as_str();
+                }
+            }
+        }
+
+        content
+    }
+
+    fn render_object(&self, schema: &Value, indent: &str) -> String {
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .map(|list| list.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+        let child_indent = format!("{}  ", indent);
+        let mut content = String::from("{\n");
+
+        if let Some(properties) = schema["properties"].as_object() {
+            for (key, property) in properties {
+                let optional = if required.contains(&key.as_str()) {
+                    ""
+                } else {
+                    "?"
+                };
+                content += comment_line(property, &child_indent).as_str();
+                content += format!(
+                    "{}{}{}: {}\n",
+                    child_indent,
+                    property_name(key),
+                    optional,
+                    self.render_type(property, &child_indent)
+                )
+                .as_str();
+            }
+        }
+
+        let additional = &schema["additionalProperties"];
+        if additional.is_object() || additional.as_bool() == Some(true) {
+            let value_type = if additional.is_object() {
+                self.render_type(additional, &child_indent)
+            } else {
+                String::from("unknown")
+            };
+            content += format!("{}[key: string]: {}\n", child_indent, value_type).as_str();
+        }
+
+        content + indent + "}"
+    }
+
+    fn render_type(&self, schema: &Value, indent: &str) -> String {
+        let ts_type = self.render_non_nullable_type(schema, indent);
+
+        if schema["nullable"].as_bool() == Some(true) {
+            format!("{} | null", ts_type)
+        } else {
+            ts_type
+        }
+    }
+
+    fn render_non_nullable_type(&self, schema: &Value, indent: &str) -> String {
+        if let Some(reference) = schema["$ref"].as_str() {
+            return type_name(reference.rsplit('/').next().unwrap_or(reference));
+        }
+
+        if let Some(list) = schema["enum"].as_array() {
+            let values: Vec<String> = list.iter().map(literal).collect();
+            return values.join(" | ");
+        }
+
+        for (key, separator) in [("allOf", " & "), ("oneOf", " | "), ("anyOf", " | ")] {
+            if let Some(list) = schema[key].as_array() {
+                let types: Vec<String> = list
+                    .iter()
+                    .map(|item| self.render_type(item, indent))
+                    .collect();
+                return types.join(separator);
+            }
+        }
+
+        match schema["type"].as_str() {
+            Some("string") if schema["format"] == "binary" => String::from("Blob"),
+            Some("string") => String::from("string"),
+            Some("integer") | Some("number") => String::from("number"),
+            Some("boolean") => String::from("boolean"),
+            Some("array") => {
+                let item = self.render_type(&schema["items"], indent);
+                if item.contains(' ') {
+                    format!("Array<{}>", item)
+                } else {
+                    format!("{}[]", item)
+                }
+            }
+            _ if is_object_schema(schema) => self.render_object(schema, indent),
+            Some("object") => String::from("Record<string, unknown>"),
+            _ => String::from("unknown"),
+        }
+    }
+
+    /// 后端接口统一返回`{ code, data, msg }`时，只取data的类型
+    fn render_response_type(&self, operation: &Value) -> String {
+        let responses = &operation["responses"];
+        let response = ["200", "201", "default"]
+            .iter()
+            .map(|code| self.resolve(&responses[*code]))
+            .find(|response| !response.is_null());
+
+        let schema = match response.and_then(|response| self.json_schema(response)) {
+            Some(schema) => schema,
+            None => return String::from("unknown"),
+        };
+
+        let resolved = self.resolve(schema);
+        let properties = &resolved["properties"];
+        if properties["code"].is_object() && properties["data"].is_object() {
+            self.render_type(&properties["data"], "")
+        } else {
+            self.render_type(schema, "")
+        }
+    }
+
+    fn json_schema<'b>(&'b self, body: &'b Value) -> Option<&'b Value> {
+        let content = body["content"].as_object()?;
+        content
+            .iter()
+            .find(|(media, _)| media.contains("json"))
+            .or_else(|| content.iter().next())
+            .map(|(_, media)| &media["schema"])
+            .filter(|schema| !schema.is_null())
+    }
+
+    fn render_api(&self, name: &str) -> Result<String, Box<dyn Error>> {
+        let paths = self.doc["paths"].as_object().ok_or("文档中没有paths")?;
+        let mut params_content = String::new();
+        let mut functions_content = String::new();
+        // 已使用的函数名和查询参数的类型名
+        let mut names = HashSet::new();
+
+        for (path, item) in paths {
+            for method in METHODS {
+                let operation = &item[method];
+                if operation.is_null() {
+                    continue;
+                }
+
+                let function = self.render_function(path, method, operation, item, &mut names);
+                params_content += function.params.as_str();
+                functions_content += function.content.as_str();
+            }
+        }
+
+        let models = self.used_models(&(params_content.clone() + functions_content.as_str()));
+        let mut content = String::from("// 由create-rzpack根据OpenAPI文档生成\n");
+        if !models.is_empty() {
+            let models: Vec<String> = models.into_iter().collect();
+            content += format!(
+                "import type {{ {} }} from '@/model/{}'\n",
+                models.join(", "),
+                name
+            )
+            .as_str();
+        }
+        content += "import type { Response } from './request'\n";
+        content += "import request from './request'\n";
+
+        Ok(content + "\n" + params_content.as_str() + functions_content.as_str())
+    }
+
+    fn render_function(
+        &self,
+        path: &str,
+        method: &str,
+        operation: &Value,
+        item: &Value,
+        names: &mut HashSet<String>,
+    ) -> RenderedFunction {
+        let function_name = match operation["operationId"].as_str() {
+            Some(id) => camel_case(id),
+            None => camel_case(&format!("{} {}", method, path)),
+        };
+        // 不同接口转换后的函数名相同时加上序号，如getUser2
+        let function_name = unique_name(&function_name, names);
+
+        // 路径上公共的参数和接口自身的参数
+        let parameters: Vec<&Value> = item["parameters"]
+            .as_array()
+            .into_iter()
+            .chain(operation["parameters"].as_array())
+            .flatten()
+            .map(|parameter| self.resolve(parameter))
+            .collect();
+
+        let mut args: Vec<Arg> = vec![];
+        let mut params = String::new();
+
+        for parameter in parameters.iter().filter(|p| p["in"] == "path") {
+            let name = parameter["name"].as_str().unwrap_or_default();
+            args.push(Arg {
+                name: camel_case(name),
+                ts_type: self.render_type(&parameter["schema"], ""),
+                description: description(parameter),
+                optional: false,
+            });
+        }
+
+        let body = self.resolve(&operation["requestBody"]);
+        let has_body = match self.json_schema(body) {
+            Some(schema) => {
+                args.push(Arg {
+                    name: String::from("data"),
+                    ts_type: self.render_type(schema, ""),
+                    description: description(body),
+                    optional: false,
+                });
+                true
+            }
+            None => false,
+        };
+
+        // 查询参数放在最后，没有必填的查询参数时可以不传
+        let query: Vec<&&Value> = parameters.iter().filter(|p| p["in"] == "query").collect();
+        let has_params = !query.is_empty();
+        if has_params {
+            let query_name = self.params_name(&function_name, names);
+            params += format!("export interface {} {{\n", query_name).as_str();
+            let mut has_required = false;
+            for parameter in query {
+                let optional = if parameter["required"].as_bool() == Some(true) {
+                    has_required = true;
+                    ""
+                } else {
+                    "?"
+                };
+                params += comment_line(parameter, "  ").as_str();
+                params += format!(
+                    "  {}{}: {}\n",
+                    property_name(parameter["name"].as_str().unwrap_or_default()),
+                    optional,
+                    self.render_type(&parameter["schema"], "  ")
+                )
+                .as_str();
+            }
+            params += "}\n";
+            args.push(Arg {
+                name: String::from("params"),
+                ts_type: query_name,
+                description: String::from("查询参数"),
+                optional: !has_required,
+            });
+        }
+
+        let url = if path.contains('{') {
+            let mut url = String::new();
+            for (i, part) in path.split('{').enumerate() {
+                match part.split_once('}') {
+                    Some((name, rest)) if i > 0 => {
+                        url += format!("${{{}}}{}", camel_case(name), rest).as_str();
+                    }
+                    _ => url += part,
+                }
+            }
+            format!("`{}`", url)
+        } else {
+            format!("'{}'", path)
+        };
+
+        // get/delete只有config参数，请求体和查询参数都放在config中
+        let call_args = match (method, has_body, has_params) {
+            ("get" | "delete", false, false) => url,
+            ("get" | "delete", true, false) => format!("{}, {{ data }}", url),
+            ("get" | "delete", false, true) => format!("{}, {{ params }}", url),
+            ("get" | "delete", true, true) => format!("{}, {{ data, params }}", url),
+            (_, true, true) => format!("{}, data, {{ params }}", url),
+            (_, false, true) => format!("{}, undefined, {{ params }}", url),
+            (_, true, false) => format!("{}, data", url),
+            _ => url,
+        };
+
+        let mut doc = String::from("/**\n");
+        let summary = operation["summary"]
+            .as_str()
+            .or(operation["description"].as_str())
+            .unwrap_or(function_name.as_str());
+        doc += format!(" * {}\n", summary.trim()).as_str();
+        for arg in &args {
+            doc += format!(" * @param {} {}", arg.name, arg.description).trim_end();
+            doc += "\n";
+        }
+        doc += " */\n";
+
+        let signature: Vec<String> = args
+            .iter()
+            .map(|arg| {
+                let optional = if arg.optional { "?" } else { "" };
+                format!("{}{}: {}", arg.name, optional, arg.ts_type)
+            })
+            .collect();
+        let content = doc
+            + format!(
+                "export const {} = ({}): Response<{}> =>\n  request.{}({})\n",
+                function_name,
+                signature.join(", "),
+                self.render_response_type(operation),
+                method,
+                call_args
+            )
+            .as_str();
+
+        RenderedFunction { params, content }
+    }
+
+    /// 查询参数的类型名，与数据模型或其他查询参数重名时改为XxxQueryParams，避免重复声明
+    fn params_name(&self, function_name: &str, names: &mut HashSet<String>) -> String {
+        let models: Vec<String> = self
+            .schemas()
+            .map(|schemas| schemas.keys().map(|name| type_name(name)).collect())
+            .unwrap_or_default();
+        let name = type_name(function_name);
+
+        let mut candidates = [format!("{}Params", name), format!("{}QueryParams", name)]
+            .into_iter()
+            .chain((2..).map(|i| format!("{}QueryParams{}", name, i)));
+        let name = candidates
+            .find(|candidate| !models.contains(candidate) && !names.contains(candidate))
+            .unwrap_or_default();
+        names.insert(name.clone());
+        name
+    }
+
+    /// 获取api文件中使用到的数据模型
+    fn used_models(&self, content: &str) -> BTreeSet<String> {
+        let mut models = BTreeSet::new();
+        if let Some(schemas) = self.schemas() {
+            for name in schemas.keys() {
+                let name = type_name(name);
+                let used = content
+                    .match_indices(name.as_str())
+                    .any(|(i, _)| is_identifier_at(content, i, name.len()));
+                if used {
+                    models.insert(name);
+                }
+            }
+        }
+
+        models
+    }
+}
+
+/// 模块名用作文件名，只允许字母开头的字母和数字，避免写到src以外的目录
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() => chars.all(|c| c.is_ascii_alphanumeric()),
+        _ => false,
+    }
+}
+
+fn unique_name(name: &str, names: &mut HashSet<String>) -> String {
+    let name = std::iter::once(name.to_string())
+        .chain((2..).map(|i| format!("{}{}", name, i)))
+        .find(|candidate| !names.contains(candidate))
+        .unwrap_or_default();
+    names.insert(name.clone());
+    name
+}
+
+struct Arg {
+    name: String,
+    ts_type: String,
+    description: String,
+    optional: bool,
+}
+
+struct RenderedFunction {
+    params: String,
+    content: String,
+}
+
+fn is_object_schema(schema: &Value) -> bool {
+    schema["properties"].is_object() || schema["additionalProperties"].is_object()
+}
+
+fn description(schema: &Value) -> String {
+    schema["description"]
+        .as_str()
+        .or(schema["title"].as_str())
+        .unwrap_or_default()
+        .replace('\n', " ")
+}
+
+fn comment_line(schema: &Value, indent: &str) -> String {
+    let desc = description(schema);
+    if desc.is_empty() {
+        String::new()
+    } else {
+        format!("{}// {}\n", indent, desc)
+    }
+}
+
+fn literal(value: &Value) -> String {
+    match value {
+        Value::String(value) => format!("'{}'", value.replace('\'', "\\'")),
+        Value::Null => String::from("null"),
+        value => value.to_string(),
+    }
+}
+
+fn property_name(name: &str) -> String {
+    let is_identifier = name.chars().enumerate().all(|(i, c)| {
+        c == '_' || c == '$' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+    });
+
+    if is_identifier && !name.is_empty() {
+        name.to_string()
+    } else {
+        format!("'{}'", name)
+    }
+}
+
+fn is_identifier_at(content: &str, pos: usize, len: usize) -> bool {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+    let before = content[..pos].chars().next_back();
+    let after = content[pos + len..].chars().next();
+
+    !before.is_some_and(is_word) && !after.is_some_and(is_word)
+}
+
+/// 将任意字符串拆分为单词，如`get /v1/user/{id}` -> [get, v1, user, id]
+fn words(input: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+    let mut prev_lower = false;
+
+    for c in input.chars() {
+        if !c.is_ascii_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            prev_lower = false;
+            continue;
+        }
+
+        if c.is_ascii_uppercase() && prev_lower {
+            words.push(std::mem::take(&mut word));
+        }
+        prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn type_name(name: &str) -> String {
+    let name: String = words(name).iter().map(|word| capitalize(word)).collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Model{}", name)
+    } else {
+        name
+    }
+}
+
+fn camel_case(name: &str) -> String {
+    let words = words(name);
+    let mut result = String::new();
+    for (i, word) in words.iter().enumerate() {
+        if i == 0 {
+            result += word.to_ascii_lowercase().as_str();
+        } else {
+            result += capitalize(word).as_str();
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn render(paths: Value) -> String {
+        let doc = json!({
+            "openapi": "3.0.0",
+            "paths": paths,
+            "components": {
+                "schemas": {
+                    "User": {
+                        "type": "object",
+                        "properties": { "id": { "type": "integer" } },
+                    },
+                    "GetUserParams": { "type": "string" },
+                },
+            },
+        });
+        Generator { doc: &doc }.render_api("user").unwrap()
+    }
+
+    fn body() -> Value {
+        json!({
+            "content": {
+                "application/json": { "schema": { "$ref": "#/components/schemas/User" } },
+            },
+        })
+    }
+
+    fn query(name: &str, required: bool) -> Value {
+        json!({ "in": "query", "name": name, "required": required, "schema": { "type": "string" } })
+    }
+
+    #[test]
+    fn get_with_optional_params() {
+        let api = render(json!({
+            "/user": {
+                "get": { "operationId": "listUsers", "parameters": [query("name", false)] },
+            },
+        }));
+
+        assert!(api.contains("export interface ListUsersParams {\n  name?: string\n}\n"));
+        assert!(api.contains(
+            "export const listUsers = (params?: ListUsersParams): Response<unknown> =>\n  request.get('/user', { params })\n"
+        ));
+    }
+
+    #[test]
+    fn get_with_required_params_and_path() {
+        let api = render(json!({
+            "/user/{id}": {
+                "parameters": [{ "in": "path", "name": "id", "required": true, "schema": { "type": "integer" } }],
+                "get": { "operationId": "getUser", "parameters": [query("fields", true)] },
+            },
+        }));
+
+        assert!(api.contains(
+            "export const getUser = (id: number, params: GetUserQueryParams): Response<unknown> =>\n  request.get(`/user/${id}`, { params })\n"
+        ));
+    }
+
+    #[test]
+    fn rename_params_conflicting_with_model() {
+        let api = render(json!({
+            "/user": {
+                "get": { "operationId": "getUser", "parameters": [query("id", true)] },
+            },
+        }));
+
+        assert!(api.contains("export interface GetUserQueryParams {"));
+        assert!(!api.contains("GetUserParams"));
+    }
+
+    #[test]
+    fn post_with_body_and_params() {
+        let api = render(json!({
+            "/user": {
+                "post": {
+                    "operationId": "createUser",
+                    "parameters": [query("notify", false)],
+                    "requestBody": body(),
+                },
+            },
+            "/user/lock": {
+                "post": { "operationId": "lockUser", "parameters": [query("id", true)] },
+            },
+            "/user/save": {
+                "post": { "operationId": "saveUser", "requestBody": body() },
+            },
+        }));
+
+        assert!(api.starts_with(
+            "// 由create-rzpack根据OpenAPI文档生成\nimport type { User } from '@/model/user'\n"
+        ));
+        assert!(api.contains(
+            "(data: User, params?: CreateUserParams): Response<unknown> =>\n  request.post('/user', data, { params })\n"
+        ));
+        assert!(api.contains(
+            "(params: LockUserParams): Response<unknown> =>\n  request.post('/user/lock', undefined, { params })\n"
+        ));
+        assert!(api
+            .contains("(data: User): Response<unknown> =>\n  request.post('/user/save', data)\n"));
+    }
+
+    #[test]
+    fn delete_with_body_and_params() {
+        let api = render(json!({
+            "/user": {
+                "delete": {
+                    "operationId": "deleteUsers",
+                    "parameters": [query("force", true)],
+                    "requestBody": body(),
+                },
+            },
+            "/user/batch": {
+                "delete": { "operationId": "deleteBatch", "requestBody": body() },
+            },
+            "/user/expired": {
+                "delete": { "operationId": "deleteExpired", "parameters": [query("days", false)] },
+            },
+            "/user/all": {
+                "delete": { "operationId": "deleteAll" },
+            },
+        }));
+
+        assert!(api.contains(
+            "(data: User, params: DeleteUsersParams): Response<unknown> =>\n  request.delete('/user', { data, params })\n"
+        ));
+        assert!(api.contains(
+            "(data: User): Response<unknown> =>\n  request.delete('/user/batch', { data })\n"
+        ));
+        assert!(api.contains(
+            "(params?: DeleteExpiredParams): Response<unknown> =>\n  request.delete('/user/expired', { params })\n"
+        ));
+        assert!(
+            api.contains("deleteAll = (): Response<unknown> =>\n  request.delete('/user/all')\n")
+        );
+    }
+
+    #[test]
+    fn render_models_from_schemas() {
+        let doc = json!({
+            "components": {
+                "schemas": {
+                    "Status": { "enum": ["on", "off"] },
+                    "user-info": {
+                        "description": "用户",
+                        "required": ["id"],
+                        "properties": {
+                            "id": { "type": "integer" },
+                            "roles": { "type": "array", "items": { "$ref": "#/components/schemas/Status" } },
+                            "remark": { "type": "string", "nullable": true },
+                        },
+                    },
+                },
+            },
+        });
+        let models = Generator { doc: &doc }.render_models();
+
+        assert!(models.contains("export type Status = 'on' | 'off'\n"));
+        assert!(models.contains(
+            "// 用户\nexport interface UserInfo {\n  id: number\n  remark?: string | null\n  roles?: Status[]\n}\n"
+        ));
+    }
+
+    #[test]
+    fn read_yaml_and_json_spec() {
+        let dir = std::env::temp_dir().join(format!("create-rzpack-api-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let yaml = dir.join("spec.yaml");
+        let json = dir.join("spec.json");
+        fs::write(&yaml, "openapi: 3.0.0\npaths: {}\n").unwrap();
+        fs::write(&json, r#"{ "openapi": "3.0.0", "paths": {} }"#).unwrap();
+
+        assert_eq!(read_spec(&yaml).unwrap()["openapi"], "3.0.0");
+        assert_eq!(read_spec(&json).unwrap()["openapi"], "3.0.0");
+        assert!(read_spec(&dir.join("missing.json")).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rename_duplicate_functions_and_params() {
+        let api = render(json!({
+            "/user": {
+                "get": { "operationId": "get_user", "parameters": [query("id", true)] },
+            },
+            "/v2/user": {
+                "get": { "operationId": "getUser", "parameters": [query("id", true)] },
+            },
+        }));
+
+        assert!(api.contains("export interface GetUserQueryParams {"));
+        assert!(api.contains("export interface GetUser2Params {"));
+        assert!(api.contains("export const getUser = (params: GetUserQueryParams)"));
+        assert!(api.contains("export const getUser2 = (params: GetUser2Params)"));
+    }
+
+    #[test]
+    fn validate_module_name() {
+        assert!(is_valid_name("system"));
+        assert!(is_valid_name("userV2"));
+        assert!(!is_valid_name("../../x"));
+        assert!(!is_valid_name("2fa"));
+        assert!(!is_valid_name("user-info"));
+        assert!(!is_valid_name(""));
+    }
+}
@@ -0,0 +1,366 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use super::source;
+use crate::utils::{file, log};
+
+const ROUTES_FILE: &str = "src/router/routes.ts";
+
+pub fn create(component: &str, title: &str, parent: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let root = file::get_current_dir();
+    let routes_path = root.join(ROUTES_FILE);
+    if !routes_path.is_file() {
+        return Err(format!("未找到{}，请在admin模板创建的项目根目录下执行", ROUTES_FILE).into());
+    }
+
+    let component = component.trim_matches('/');
+    let segments: Vec<&str> = component.split('/').collect();
+    if !segments.iter().all(|segment| is_valid_segment(segment)) {
+        return Err(format!(
+            "页面路径{}不合法，应为以字母开头的大驼峰命名，如System/Role",
+            component
+        )
+        .into());
+    }
+
+    let page_dir = root.join("src/pages").join(component);
+    if page_dir.exists() {
+        return Err(format!("页面目录{:?}已存在", page_dir).into());
+    }
+
+    // 先创建页面，注册路由失败时删除新建的目录，避免routes.ts引用不存在的组件
+    let created_dir = page_dir
+        .ancestors()
+        .take_while(|dir| !dir.exists())
+        .last()
+        .unwrap_or(&page_dir)
+        .to_path_buf();
+    write_page(&page_dir, segments[segments.len() - 1], title)?;
+    if let Err(e) = register(&root, component, title, parent, &[]) {
+        fs::remove_dir_all(&created_dir)?;
+        return Err(e);
+    }
+
+    Ok(())
+}
+
+/// 在项目的src/router/routes.ts中注册路由，permissions为访问路由所需的权限
+pub fn register(
+    root: &Path,
+    component: &str,
+    title: &str,
+    parent: Option<&str>,
+    permissions: &[&str],
+) -> Result<(), Box<dyn Error>> {
+    let routes_path = root.join(ROUTES_FILE);
+    let routes = fs::read_to_string(&routes_path)?;
+    let (routes, path) = add_route(&routes, component, title, parent, permissions)?;
+    fs::write(&routes_path, routes)?;
+    log::info(format!("注册路由成功：{} -> {}", path, component));
+
+    Ok(())
+}
+
+/// 在routes.ts的内容中添加路由，返回添加后的内容及路由地址
+fn add_route(
+    routes: &str,
+    component: &str,
+    title: &str,
+    parent: Option<&str>,
+    permissions: &[&str],
+) -> Result<(String, String), Box<dyn Error>> {
+    let segments: Vec<&str> = component.split('/').collect();
+    let parent = parent.map(normalize_path);
+    let path = get_route_path(&segments, parent.as_deref());
+
+    if find_path_property(routes, &path).is_some() {
+        return Err(format!("路由{}已存在", path).into());
+    }
+
+    let route = Route {
+        component,
+        title,
+        path: &path,
+        permissions,
+    };
+    let routes = insert_route(routes, &route, parent.as_deref())?;
+
+    Ok((routes, path))
+}
+
+fn is_valid_segment(segment: &str) -> bool {
+    let mut chars = segment.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_uppercase() => chars.all(|c| c.is_ascii_alphanumeric()),
+        _ => false,
+    }
+}
+
+/// 统一路由地址的格式为`/xxx/xxx`
+fn normalize_path(path: &str) -> String {
+    format!("/{}", path.trim().trim_matches('/'))
+}
+
+/// 将大驼峰命名转换为路由地址中使用的中划线命名，如UserDetails -> user-details
+fn kebab_case(segment: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in segment.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                result.push('-');
+            }
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+fn get_route_path(segments: &[&str], parent: Option<&str>) -> String {
+    match parent {
+        Some(parent) => format!(
+            "{}/{}",
+            parent.trim_end_matches('/'),
+            kebab_case(segments[segments.len() - 1])
+        ),
+        None => {
+            let paths: Vec<String> = segments.iter().map(|segment| kebab_case(segment)).collect();
+            format!("/{}", paths.join("/"))
+        }
+    }
+}
+
+/// 查找`path: '/xxx'`属性所在的位置
+fn find_path_property(routes: &str, path: &str) -> Option<usize> {
+    [format!("path: '{}'", path), format!("path: \"{}\"", path)]
+        .iter()
+        .find_map(|pattern| routes.find(pattern.as_str()))
+}
+
+struct Route<'a> {
+    component: &'a str,
+    title: &'a str,
+    path: &'a str,
+    permissions: &'a [&'a str],
+}
+
+fn insert_route(
+    routes: &str,
+    route: &Route,
+    parent: Option<&str>,
+) -> Result<String, Box<dyn Error>> {
+    let pairs = source::match_brackets(routes);
+
+    let (children, indent) = match parent {
+        Some(parent) => {
+            let pos = find_path_property(routes, parent)
+                .ok_or_else(|| format!("未找到父级路由{}", parent))?;
+            let object = source::enclosing_object(routes, &pairs, pos)
+                .ok_or_else(|| format!("无法解析父级路由{}", parent))?;
+            let indent = source::line_indent(routes, pos);
+
+            match source::find_array_property(routes, &pairs, object, "children") {
+                Some(children) => (children, indent + "  "),
+                None => {
+                    // 父级路由没有children时，添加children作为第一个属性(属性按字母排序)
+                    let entry = render_route(route, &format!("{}  ", indent));
+                    let insert_at = source::line_start(routes, pos);
+                    let insert_at = routes[object.0..insert_at]
+                        .find('\n')
+                        .map_or(insert_at, |i| object.0 + i + 1);
+                    let children = format!("{}children: [\n{}{}],\n", indent, entry, indent);
+
+                    return Ok(format!(
+                        "{}{}{}",
+                        &routes[..insert_at],
+                        children,
+                        &routes[insert_at..]
+                    ));
+                }
+            }
+        }
+        None => {
+            let pos = routes
+                .find("RouteModel[] = [")
+                .ok_or("无法解析routes数组")?
+                + "RouteModel[] = ".len();
+            let close = pairs.get(&pos).ok_or("无法解析routes数组")?;
+            ((pos, *close), String::from("  "))
+        }
+    };
+
+    let (open, close) = children;
+    let entry = render_route(route, &indent);
+    let insert_at = source::line_start(routes, close).max(open + 1);
+    let before = routes[..insert_at].trim_end();
+
+    // 上一个元素没有尾逗号时补上
+    let separator = if before.ends_with(',') || before.ends_with('[') {
+        ""
+    } else {
+        ","
+    };
+
+    Ok(format!(
+        "{}{}\n{}{}",
+        before,
+        separator,
+        entry,
+        &routes[insert_at..]
+    ))
+}
+
+fn render_route(route: &Route, indent: &str) -> String {
+    let title = source::escape_js_string(route.title);
+    // 属性按字母排序
+    let permissions = if route.permissions.is_empty() {
+        String::new()
+    } else {
+        let permissions: Vec<String> = route
+            .permissions
+            .iter()
+            .map(|item| format!("'{}'", item))
+            .collect();
+        format!("{indent}  permissions: [{}],\n", permissions.join(", "))
+    };
+
+    format!("{indent}{{\n")
+        + format!("{indent}  component: '{}',\n", route.component).as_str()
+        + format!("{indent}  hidden: false,\n").as_str()
+        + format!("{indent}  path: '{}',\n", route.path).as_str()
+        + permissions.as_str()
+        + format!("{indent}  title: '{}',\n", title).as_str()
+        + format!("{indent}}},\n").as_str()
+}
+
+fn write_page(page_dir: &Path, name: &str, title: &str) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(page_dir)?;
+
+    let content = String::from("import React from 'react'\n\n")
+        + format!("const {} = () => {{\n", name).as_str()
+        + format!("  return <div>{}</div>\n", source::escape_jsx_text(title)).as_str()
+        + "}\n\n"
+        + format!("export default {}\n", name).as_str();
+
+    let path = page_dir.join("index.tsx");
+    fs::write(&path, content)?;
+    log::info(format!("创建文件成功：{:?}", path));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROUTES: &str = "import type { RouteModel } from './tools'
+
+const routes: RouteModel[] = [
+  {
+    children: [
+      {
+        component: 'System/User',
+        hidden: false,
+        path: '/system/user',
+        title: '用户管理',
+      },
+    ],
+    hidden: false,
+    path: '/system',
+    title: '系统管理',
+  },
+  {
+    component: 'Home',
+    hidden: false,
+    path: '/home',
+    title: '首页',
+  },
+]
+";
+
+    #[test]
+    fn add_route_to_parent_children() {
+        let (routes, path) =
+            add_route(ROUTES, "System/Role", "角色管理", Some("/system"), &[]).unwrap();
+
+        assert_eq!(path, "/system/role");
+        assert!(routes.contains(
+            "        title: '用户管理',
+      },
+      {
+        component: 'System/Role',
+        hidden: false,
+        path: '/system/role',
+        title: '角色管理',
+      },
+    ],"
+        ));
+    }
+
+    #[test]
+    fn add_children_to_parent_without_children() {
+        let (routes, path) = add_route(
+            ROUTES,
+            "Home/Notice",
+            "通知",
+            Some("home/"),
+            &["home:notice"],
+        )
+        .unwrap();
+
+        assert_eq!(path, "/home/notice");
+        assert!(routes.contains(
+            "  {
+    children: [
+      {
+        component: 'Home/Notice',
+        hidden: false,
+        path: '/home/notice',
+        permissions: ['home:notice'],
+        title: '通知',
+      },
+    ],
+    component: 'Home',"
+        ));
+    }
+
+    #[test]
+    fn add_top_level_route() {
+        let (routes, path) = add_route(ROUTES, "UserDetails", "用户详情", None, &[]).unwrap();
+
+        assert_eq!(path, "/user-details");
+        assert!(routes.ends_with(
+            "    title: '首页',
+  },
+  {
+    component: 'UserDetails',
+    hidden: false,
+    path: '/user-details',
+    title: '用户详情',
+  },
+]
+"
+        ));
+    }
+
+    #[test]
+    fn reject_duplicate_path() {
+        let error = add_route(ROUTES, "System/User", "用户", Some("/system"), &[]).unwrap_err();
+        assert_eq!(error.to_string(), "路由/system/user已存在");
+    }
+
+    #[test]
+    fn reject_missing_parent() {
+        let error = add_route(ROUTES, "Report/Daily", "日报", Some("/report"), &[]).unwrap_err();
+        assert_eq!(error.to_string(), "未找到父级路由/report");
+    }
+
+    #[test]
+    fn escape_title() {
+        let (routes, _) = add_route(ROUTES, "Faq", "It's a\\b", None, &[]).unwrap();
+        assert!(routes.contains("title: 'It\\'s a\\\\b',"));
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+/// 匹配ts源码中的括号(`{}`和`[]`)，返回左括号位置到右括号位置的映射
+/// 字符串、模板字符串和注释中的括号会被忽略
+pub fn match_brackets(source: &str) -> HashMap<usize, usize> {
+    let bytes = source.as_bytes();
+    let mut pairs = HashMap::new();
+    let mut stack: Vec<usize> = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'{' | b'[' => stack.push(i),
+            b'}' | b']' => {
+                if let Some(open) = stack.pop() {
+                    pairs.insert(open, i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    pairs
+}
+
+/// 获取包含指定位置的最内层对象的括号位置
+pub fn enclosing_object(
+    source: &str,
+    pairs: &HashMap<usize, usize>,
+    pos: usize,
+) -> Option<(usize, usize)> {
+    pairs
+        .iter()
+        .filter(|(open, close)| source.as_bytes()[**open] == b'{' && **open < pos && pos < **close)
+        .max_by_key(|(open, _)| **open)
+        .map(|(open, close)| (*open, *close))
+}
+
+/// 在对象的第一层属性中查找指定key的值为数组的括号位置
+pub fn find_array_property(
+    source: &str,
+    pairs: &HashMap<usize, usize>,
+    object: (usize, usize),
+    key: &str,
+) -> Option<(usize, usize)> {
+    let (open, close) = object;
+    let mut i = open + 1;
+
+    while i < close {
+        // 跳过嵌套的对象和数组
+        if let Some(end) = pairs.get(&i) {
+            i = end + 1;
+            continue;
+        }
+
+        // 跳过字符串
+        let quote = source.as_bytes()[i];
+        if quote == b'\'' || quote == b'"' || quote == b'`' {
+            i += 1;
+            while i < close && source.as_bytes()[i] != quote {
+                if source.as_bytes()[i] == b'\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            continue;
+        }
+
+        let rest = &source[i..close];
+        if rest.starts_with(key) && is_word_boundary(source, i) {
+            let value = rest[key.len()..].trim_start();
+            if let Some(value) = value.strip_prefix(':') {
+                let value_start = close - value.trim_start().len();
+                if source.as_bytes()[value_start] == b'[' {
+                    return pairs.get(&value_start).map(|end| (value_start, *end));
+                }
+            }
+        }
+
+        i += rest.chars().next().map_or(1, |c| c.len_utf8());
+    }
+
+    None
+}
+
+/// 获取指定位置所在行的缩进
+pub fn line_indent(source: &str, pos: usize) -> String {
+    let line_start = line_start(source, pos);
+    source[line_start..]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect()
+}
+
+/// 获取指定位置所在行的起始位置
+pub fn line_start(source: &str, pos: usize) -> usize {
+    source[..pos].rfind('\n').map_or(0, |i| i + 1)
+}
+
+fn is_word_boundary(source: &str, pos: usize) -> bool {
+    source[..pos]
+        .chars()
+        .next_back()
+        .is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '$'))
+}
+
+/// 转义单引号字符串中的内容
+pub fn escape_js_string(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+/// 转义JSX文本中有特殊含义的字符
+pub fn escape_jsx_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('{', "&#123;")
+        .replace('}', "&#125;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignore_brackets_in_strings_and_comments() {
+        let source = "const a = { b: '{[', c: `]}`, // }\n d: [1] /* { */ }";
+        let pairs = match_brackets(source);
+
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[&10], source.len() - 1);
+        let array = source.find("[1]").unwrap();
+        assert_eq!(pairs[&array], array + 2);
+    }
+
+    #[test]
+    fn find_first_level_array_property() {
+        let source = "{ meta: { children: [1] }, title: 'children: [2]', children: [3] }";
+        let pairs = match_brackets(source);
+        let object = (0, source.len() - 1);
+        let (open, close) = find_array_property(source, &pairs, object, "children").unwrap();
+
+        assert_eq!(&source[open..=close], "[3]");
+        assert!(find_array_property(source, &pairs, object, "title").is_none());
+    }
+
+    #[test]
+    fn find_enclosing_object_and_indent() {
+        let source = "[\n  {\n    path: '/a',\n  },\n]";
+        let pairs = match_brackets(source);
+        let pos = source.find("path").unwrap();
+
+        assert_eq!(enclosing_object(source, &pairs, pos), Some((4, 24)));
+        assert_eq!(line_indent(source, pos), "    ");
+        assert_eq!(line_start(source, pos), 6);
+    }
+
+    #[test]
+    fn escape_text() {
+        assert_eq!(escape_js_string("It's a\\b"), "It\\'s a\\\\b");
+        assert_eq!(
+            escape_jsx_text("{a} <b> & c"),
+            "&#123;a&#125; &lt;b&gt; &amp; c"
+        );
+    }
+}
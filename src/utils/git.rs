@@ -0,0 +1,93 @@
+use std::path::Path;
+
+use crate::utils::{
+    log,
+    tools::{run_command, CommandError, CommandOptions, CommandOutput},
+};
+
+/// 初始化git仓库的选项，使用--no-git时为None
+#[derive(Debug, Clone)]
+pub struct GitOptions {
+    /// 默认分支
+    pub branch: String,
+    /// 是否创建初始提交，使用--no-commit时为false
+    pub commit: bool,
+    /// 初始提交的提交信息
+    pub message: String,
+    /// 远程仓库地址，添加为origin
+    pub remote: Option<String>,
+}
+
+/// 在指定目录中执行git命令
+fn git(root: &Path, args: &[&str]) -> Result<CommandOutput, CommandError> {
+    let options = CommandOptions {
+        cwd: Some(root),
+        // 远程仓库需要认证时直接失败，不等待输入
+        envs: vec![("GIT_TERMINAL_PROMPT", "0")],
+        ..Default::default()
+    };
+    run_command("git", args, &options)
+}
+
+/// 目录是否已位于git仓库中
+pub fn is_inside_repo(root: &Path) -> bool {
+    git(root, &["rev-parse", "--is-inside-work-tree"]).is_ok()
+}
+
+/// 在项目根目录中初始化git仓库并设置默认分支、远程仓库，返回是否新建了仓库
+pub fn init(root: &Path, options: &GitOptions) -> bool {
+    if is_inside_repo(root) {
+        log::info("项目已位于git仓库中，跳过初始化git仓库".to_string());
+        return false;
+    }
+
+    log::info("正在初始化git仓库...".to_string());
+    if let Err(e) = git(root, &["init"]) {
+        log::error(format!("初始化git仓库失败: {}", e));
+        return false;
+    }
+
+    // 还没有提交时可以直接修改HEAD指向的分支，兼容不支持init -b的git版本
+    let head = format!("refs/heads/{}", options.branch);
+    if let Err(e) = git(root, &["symbolic-ref", "HEAD", head.as_str()]) {
+        log::error(format!("设置默认分支{}失败: {}", options.branch, e));
+    }
+
+    if let Some(remote) = &options.remote {
+        if let Err(e) = git(root, &["remote", "add", "origin", remote.as_str()]) {
+            log::error(format!("添加远程仓库{}失败: {}", remote, e));
+        }
+    }
+
+    true
+}
+
+/// 是否配置了提交需要的user.name和user.email
+fn has_identity(root: &Path) -> bool {
+    ["user.name", "user.email"]
+        .iter()
+        .all(|key| git(root, &["config", key]).is_ok_and(|output| !output.stdout.trim().is_empty()))
+}
+
+/// 创建初始提交，需要在init之后调用
+pub fn commit(root: &Path, options: &GitOptions) {
+    if !options.commit {
+        return;
+    }
+    if !has_identity(root) {
+        log::info("未配置git的user.name或user.email，跳过初始提交，配置后请手动提交".to_string());
+        return;
+    }
+
+    // 依赖可能还未安装，git钩子不会生效，跳过校验避免提交失败
+    let committed = git(root, &["add", "-A"]).and_then(|_| {
+        git(
+            root,
+            &["commit", "--no-verify", "-m", options.message.as_str()],
+        )
+    });
+    match committed {
+        Ok(_) => log::info(format!("已创建初始提交：{}", options.message)),
+        Err(e) => log::error(format!("创建初始提交失败: {}", e)),
+    }
+}
@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::utils::{
+    log, pkg,
+    tools::{run_command, CommandOptions},
+};
+
+/// 项目创建完成后在项目根目录中执行的命令
+#[derive(Deserialize, Debug, Clone)]
+pub struct Hook {
+    /// 步骤名称
+    pub name: String,
+    /// 执行的命令，通过sh -c(Windows下为cmd /C)执行，支持{install}、{package_manager}占位符
+    pub run: String,
+    /// 失败后是否继续执行后续步骤
+    #[serde(default)]
+    pub continue_on_error: bool,
+}
+
+/// 用户预设文件，格式与模板清单中的`[[hooks]]`相同
+#[derive(Deserialize, Default)]
+struct Preset {
+    #[serde(default)]
+    hooks: Vec<Hook>,
+}
+
+/// 读取用户预设文件中的命令
+pub fn load_preset(path: &Path) -> Result<Vec<Hook>, Box<dyn Error>> {
+    let content = fs::read_to_string(path)?;
+    let preset: Preset = toml::from_str(&content)?;
+    Ok(preset.hooks)
+}
+
+fn get_command(hook: &Hook, package_manager: &str) -> String {
+    hook.run
+        .replace("{install}", &pkg::get_install_command(package_manager))
+        .replace("{package_manager}", package_manager)
+}
+
+/// 依次执行命令，失败且不允许继续时跳过后续命令，返回是否全部执行成功
+pub fn run(hooks: &[Hook], root: &Path, package_manager: &str) -> bool {
+    let mut success = true;
+    let options = CommandOptions {
+        cwd: Some(root),
+        stream: true,
+        prefix: "  │ ",
+        ..Default::default()
+    };
+
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+
+    for (i, hook) in hooks.iter().enumerate() {
+        let command = get_command(hook, package_manager);
+        if command.trim().is_empty() {
+            continue;
+        }
+
+        log::info(format!(
+            "[{}/{}] {}：{}",
+            i + 1,
+            hooks.len(),
+            hook.name,
+            command
+        ));
+        let start = Instant::now();
+        // 通过shell执行，支持引号、管道等写法
+        let result = run_command(shell, &[flag, &command], &options);
+        let elapsed = start.elapsed().as_secs_f32();
+
+        match result {
+            Ok(_) => log::info(format!("✔ {}完成，耗时{:.1}s", hook.name, elapsed)),
+            Err(e) => {
+                success = false;
+                log::error(format!("✘ {}失败，耗时{:.1}s: {}", hook.name, elapsed, e));
+                if !hook.continue_on_error {
+                    log::error("已跳过后续命令，请手动执行".to_string());
+                    break;
+                }
+            }
+        }
+    }
+
+    success
+}
@@ -1,8 +1,12 @@
 pub mod args;
 pub mod file;
+pub mod generate;
+pub mod git;
+pub mod hooks;
 pub mod json;
 pub mod log;
 pub mod pkg;
 pub mod prompts;
 pub mod render;
 pub mod tools;
+pub mod workspace;
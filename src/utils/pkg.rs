@@ -35,6 +35,31 @@ pub fn get_pkg() -> Package {
     Package { name, version }
 }
 
+/// 安装依赖的命令
+pub fn get_install_command(package_manager: &str) -> String {
+    match package_manager {
+        "yarn" => String::from("yarn"),
+        _ => format!("{} install", package_manager),
+    }
+}
+
+/// CI中根据lock文件安装依赖的命令
+pub fn get_ci_install_command(package_manager: &str) -> String {
+    match package_manager {
+        "yarn" => String::from("yarn install --frozen-lockfile"),
+        "pnpm" => String::from("pnpm install --frozen-lockfile"),
+        _ => String::from("npm ci"),
+    }
+}
+
+/// 运行package.json中scripts的命令
+pub fn get_run_command(package_manager: &str, script: &str) -> String {
+    match package_manager {
+        "yarn" => format!("yarn {}", script),
+        _ => format!("{} run {}", package_manager, script),
+    }
+}
+
 pub fn is_valid_package_name(name: &str) -> bool {
     let pattern = Regex::new(r"^(?:@[a-z0-9-*~][a-z0-9-*._~]*/)?[a-z0-9-~][a-z0-9-._~]*$").unwrap();
 
@@ -1,22 +1,67 @@
-use crate::utils::{file, log, pkg};
+use crate::utils::{
+    file,
+    git::GitOptions,
+    hooks::{self, Hook},
+    log, pkg,
+    workspace::{self, Workspace},
+};
 
 use super::args::Args;
 use dialoguer::{Confirm, Input, Select};
 use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Prompts {
     pub project_name: String,
-    pub overwrite: bool,
+    /// 目标目录不为空时的处理方式，remove删除已有文件，merge合并到已有目录，为空表示目录为空
+    pub existing: String,
     pub package_name: String,
+    pub package_manager: String,
     pub template: String,
+    /// 微前端(module federation)中的角色，host为主应用，remote为子应用
+    pub federation: String,
+    pub layout: String,
+    pub rbac: bool,
+    pub state: String,
+    pub http: String,
+    pub react_query: bool,
+    pub router_mode: String,
+    pub app_title: String,
+    pub primary_color: String,
+    pub border_radius: u8,
+    pub theme_algorithm: String,
+    pub dark_mode: bool,
     pub jts_loader: String,
     pub css_scoped: bool,
     pub js_lint: String,
     pub style_lint: bool,
     pub commit_lint: bool,
     pub rs: bool,
+    pub test: String,
+    pub e2e: bool,
+    pub mock: bool,
+    pub ci: String,
+    pub docker: bool,
+    pub api_base_url: String,
+    pub root: PathBuf,
+    pub workspace: Option<Workspace>,
+    pub git: Option<GitOptions>,
+    /// 是否在创建完成后执行模板及预设中声明的命令
+    pub post_create: bool,
+    /// 用户预设中的命令，在模板声明的命令之后执行
+    pub hooks: Vec<Hook>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MonorepoPrompts {
+    pub project_name: String,
+    /// 目标目录不为空时的处理方式，remove删除已有文件，merge合并到已有目录，为空表示目录为空
+    pub existing: String,
+    pub package_name: String,
+    /// apps目录下的应用名称
+    pub apps: Vec<String>,
     pub root: PathBuf,
+    pub git: Option<GitOptions>,
 }
 
 #[derive(Debug, Clone)]
@@ -26,65 +71,348 @@ struct SelectOption {
 }
 
 pub fn get_prompts(args: Args) -> Prompts {
-    let project_name = get_project_name(args.project_name);
-    let project_name = project_name.as_str();
+    let git = get_git_options(&args);
+    let hooks = get_preset_hooks(args.preset.as_deref());
+    let project_dir = get_project_dir(args.project.or(args.project_name));
+    let current_dir = file::get_current_dir();
+    // 在pnpm/yarn/npm工作区中创建时，只填写名称的项目放到工作区的包目录下
+    let root = match workspace::detect(current_dir.as_path()) {
+        Some(workspace) if is_name(&project_dir) => workspace.packages_dir().join(&project_dir),
+        _ => file::resolve_path(current_dir.as_path(), Path::new(&project_dir)),
+    };
+    // 项目路径可能不在当前目录下，从项目的上级目录查找所在的工作区
+    let workspace = root.parent().and_then(workspace::detect);
     // 是否需要覆盖
-    let overwrite = overwrite_dir(project_name, args.force);
-    let template = get_template_value(args.template);
+    let existing = get_existing_value(&root, args.force);
+    // 项目名称取目录名，如apps/admin为admin，.为当前目录名
+    let project_name = file::get_dir_name(&root);
+    let project_name = project_name.as_str();
+    // admin_header_menu模板已合并到admin模板中，对应mixed布局
+    let (template, layout) = match args.template.as_deref() {
+        Some("admin_header_menu") => (Some(String::from("admin")), Some(String::from("mixed"))),
+        _ => (args.template, args.layout),
+    };
+    let template = get_template_value(template);
+    // 微前端的主应用基于admin模板，子应用基于antd模板
+    let (template, federation) = match template {
+        "mf_host" => ("admin", "host"),
+        "mf_remote" => ("antd", "remote"),
+        _ => (template, ""),
+    };
+    let is_admin_template = template == "admin";
+    let layout = if is_admin_template {
+        get_layout_value(layout)
+    } else {
+        ""
+    };
+    let rbac = is_admin_template && get_confirm_value("是否使用基于角色的权限控制(RBAC)?");
+    let state = if is_admin_template {
+        get_state_value()
+    } else {
+        ""
+    };
+    let http = if is_admin_template {
+        get_http_value()
+    } else {
+        ""
+    };
+    let react_query = is_admin_template && get_confirm_value("是否使用React Query?");
+    let router_mode = if is_admin_template {
+        get_router_mode_value()
+    } else {
+        ""
+    };
+    let app_title = get_app_title(project_name);
+    // antd主题只在使用antd的模板中配置
+    let is_antd_template = template != "react_ts";
+    let (primary_color, border_radius, theme_algorithm) = if is_antd_template {
+        (
+            get_primary_color(),
+            get_border_radius(),
+            get_theme_algorithm_value(),
+        )
+    } else {
+        (String::new(), 0, "")
+    };
+    let dark_mode = is_admin_template && get_confirm_value("是否开启暗色模式切换?");
     let package_name = get_package_name(project_name);
+    // 工作区中沿用工作区的包管理工具
+    let package_manager = match &workspace {
+        Some(workspace) => workspace.package_manager.clone(),
+        None => get_package_manager_value().to_string(),
+    };
     let jts_loader = get_jts_loader_value();
     let js_lint = get_js_lint_value();
     let css_scoped = get_confirm_value("是否使用Css Scoped?");
     let style_lint = get_confirm_value("是否使用styleLint?");
-    let commit_lint = get_confirm_value("是否使用CommitLint?");
+    // 工作区中的git钩子由根目录统一管理
+    let commit_lint = workspace.is_none() && get_confirm_value("是否使用CommitLint?");
     let rs = get_confirm_value("是否开启配置文件更改自动重启?");
-
-    let root = file::resolve_path(file::get_current_dir().as_path(), Path::new(project_name));
+    let test = get_test_value(args.test);
+    let e2e = is_admin_template && get_confirm_value("是否使用Playwright进行E2E测试?");
+    let mock = is_admin_template && get_confirm_value("是否使用Mock接口服务?");
+    let ci = get_ci_value();
+    // 组件库发布到npm，不需要部署
+    let docker = template != "lib" && get_confirm_value("是否生成Docker部署配置?");
+    let api_base_url = get_api_base_url();
+    let post_create = get_confirm_value("是否在创建完成后安装依赖并执行初始化命令?");
 
     Prompts {
         project_name: project_name.to_string(),
-        overwrite,
+        existing: existing.to_string(),
         root,
         package_name: package_name.to_string(),
+        package_manager,
         template: template.to_string(),
+        federation: federation.to_string(),
+        layout: layout.to_string(),
+        rbac,
+        state: state.to_string(),
+        http: http.to_string(),
+        react_query,
+        router_mode: router_mode.to_string(),
+        app_title,
+        primary_color,
+        border_radius,
+        theme_algorithm: theme_algorithm.to_string(),
+        dark_mode,
         jts_loader: jts_loader.to_string(),
         css_scoped,
         js_lint: js_lint.to_string(),
         style_lint,
         commit_lint,
         rs,
+        test: test.to_string(),
+        e2e,
+        mock,
+        ci: ci.to_string(),
+        docker,
+        api_base_url,
+        workspace,
+        git,
+        post_create,
+        hooks,
+    }
+}
+
+pub fn get_monorepo_prompts(args: &Args) -> MonorepoPrompts {
+    let project_dir = get_project_dir(args.project.clone().or(args.project_name.clone()));
+    let root = file::resolve_path(
+        file::get_current_dir().as_path(),
+        Path::new(project_dir.as_str()),
+    );
+    let existing = get_existing_value(&root, args.force);
+    let project_name = file::get_dir_name(&root);
+    let package_name = get_package_name(project_name.as_str());
+    let apps = get_app_names();
+
+    MonorepoPrompts {
+        project_name,
+        existing: existing.to_string(),
+        package_name,
+        apps,
+        root,
+        git: get_git_options(args),
+    }
+}
+
+fn get_git_options(args: &Args) -> Option<GitOptions> {
+    if args.no_git {
+        None
+    } else {
+        Some(GitOptions {
+            branch: args.branch.clone(),
+            commit: !args.no_commit,
+            message: args.commit_message.clone(),
+            remote: args.remote.clone(),
+        })
+    }
+}
+
+fn get_preset_hooks(preset: Option<&Path>) -> Vec<Hook> {
+    let Some(path) = preset else {
+        return vec![];
+    };
+
+    match hooks::load_preset(path) {
+        Ok(hooks) => hooks,
+        Err(e) => {
+            log::error(format!("读取预设文件{:?}出错: {}", path, e));
+            std::process::exit(1);
+        }
     }
 }
 
-fn get_project_name(project_name: String) -> String {
-    let project_name: String = Input::new()
+fn get_app_names() -> Vec<String> {
+    let apps: String = Input::new()
+        .with_prompt(log::yellow("应用名称(多个用逗号分隔)"))
+        .default(String::from("admin"))
+        .validate_with(|input: &String| -> Result<(), &str> {
+            let apps: Vec<&str> = input.split(',').map(|app| app.trim()).collect();
+            // 应用名称同时作为apps下的目录名，不能带scope
+            let is_valid = |app: &&str| pkg::is_valid_package_name(app) && !app.starts_with('@');
+            if !apps.iter().all(is_valid) {
+                Err("应用名称错误")
+            } else if (1..apps.len()).any(|i| apps[..i].contains(&apps[i])) {
+                Err("应用名称重复")
+            } else {
+                Ok(())
+            }
+        })
+        .interact_text()
+        .unwrap();
+
+    apps.split(',').map(|app| app.trim().to_string()).collect()
+}
+
+/// 项目目录，通过参数传入时不再询问
+fn get_project_dir(project_dir: Option<String>) -> String {
+    if let Some(project_dir) = project_dir.filter(|dir| !dir.trim().is_empty()) {
+        return project_dir.trim().to_string();
+    }
+
+    let project_dir: String = Input::new()
         .with_prompt(log::yellow("项目名称"))
         .allow_empty(false)
-        .with_initial_text(project_name)
+        .with_initial_text(String::from("rzpack-app"))
         .interact_text()
         .unwrap();
 
-    project_name.trim().to_string()
+    project_dir.trim().to_string()
 }
 
-fn overwrite_dir(project_name: &str, force: Option<bool>) -> bool {
-    // 是否需要覆盖
-    let can_overwrite = file::can_overwrite_directory(project_name);
-    let dir = if project_name == "." {
+/// 是否只是名称而不是路径，如admin，而不是apps/admin或.
+fn is_name(project_dir: &str) -> bool {
+    !project_dir.contains(['/', '\\']) && project_dir != "." && project_dir != ".."
+}
+
+/// 目标目录不为空(忽略.git)时选择处理方式，--force时直接删除已有文件
+fn get_existing_value(root: &Path, force: Option<bool>) -> &'static str {
+    if root.exists() && !root.is_dir() {
+        log::error(format!(
+            "{}已存在且不是目录，请更换项目路径",
+            root.to_string_lossy()
+        ));
+        std::process::exit(1);
+    }
+    if file::is_empty_dir(root) {
+        return "";
+    }
+    if force == Some(true) {
+        return "remove";
+    }
+
+    let dir = if root == file::get_current_dir() {
         "当前目录".to_owned()
     } else {
-        format!("{}{}", "目标目录", project_name)
+        format!("{}{}", "目标目录", root.to_string_lossy())
     };
-    let overwrite = can_overwrite == true || force == Some(true);
-    if overwrite {
-        if !get_confirm_value(
-            log::red(format!("{}不为空，是否删除{}并继续?", dir, dir).as_str()).as_str(),
-        ) {
-            panic!("操作取消")
+    let options = vec![
+        SelectOption {
+            value: "cancel",
+            name: log::cyan("取消"),
+        },
+        SelectOption {
+            value: "remove",
+            name: log::red("删除已有文件(保留.git)"),
+        },
+        SelectOption {
+            value: "merge",
+            name: log::blue("合并到已有目录，冲突的文件逐个确认"),
+        },
+    ];
+    match get_select_value(options, format!("{}不为空", dir).as_str()) {
+        "cancel" => {
+            log::error("操作取消".to_string());
+            std::process::exit(1);
         }
+        value => value,
+    }
+}
+
+/// 合并时已有文件与生成的文件冲突的处理方式
+pub fn get_conflict_value(file: &Path) -> &'static str {
+    let options = vec![
+        SelectOption {
+            value: "skip",
+            name: log::cyan("保留已有文件"),
+        },
+        SelectOption {
+            value: "overwrite",
+            name: log::red("覆盖"),
+        },
+        SelectOption {
+            value: "skip_all",
+            name: log::cyan("保留所有冲突的已有文件"),
+        },
+        SelectOption {
+            value: "overwrite_all",
+            name: log::red("覆盖所有冲突的文件"),
+        },
+    ];
+    get_select_value(
+        options,
+        format!("文件{}已存在", file.to_string_lossy()).as_str(),
+    )
+}
+
+fn get_api_base_url() -> String {
+    let api_base_url: String = Input::new()
+        .with_prompt(log::yellow("接口请求的基础地址"))
+        .default(String::from("/api"))
+        .interact_text()
+        .unwrap();
+
+    api_base_url.trim().to_string()
+}
+
+fn get_app_title(project_name: &str) -> String {
+    let app_title: String = Input::new()
+        .with_prompt(log::yellow("应用标题"))
+        .default(project_name.to_string())
+        .interact_text()
+        .unwrap();
+
+    app_title.trim().to_string()
+}
+
+fn is_hex_color(color: &str) -> bool {
+    match color.strip_prefix('#') {
+        Some(hex) => [3, 6, 8].contains(&hex.len()) && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => false,
     }
+}
+
+fn get_primary_color() -> String {
+    let color: String = Input::new()
+        .with_prompt(log::yellow("主题色"))
+        .default(String::from("#4170ff"))
+        .validate_with(|input: &String| -> Result<(), &str> {
+            if is_hex_color(input.trim()) {
+                Ok(())
+            } else {
+                Err("主题色需要是十六进制颜色，如#4170ff")
+            }
+        })
+        .interact_text()
+        .unwrap();
+
+    color.trim().to_lowercase()
+}
 
-    overwrite
+fn get_border_radius() -> u8 {
+    Input::new()
+        .with_prompt(log::yellow("圆角大小(px)"))
+        .default(6)
+        .validate_with(|input: &u8| -> Result<(), &str> {
+            if *input <= 32 {
+                Ok(())
+            } else {
+                Err("圆角大小需要在0-32之间")
+            }
+        })
+        .interact_text()
+        .unwrap()
 }
 
 fn get_package_name(project_name: &str) -> String {
@@ -120,27 +448,130 @@ fn get_template_value(template: Option<String>) -> &'static str {
         },
         SelectOption {
             value: "admin",
-            name: log::blue("admin - 基础后台管理平台(侧边菜单版)的模版"),
+            name: log::blue("admin - 基础后台管理平台的模版"),
         },
         SelectOption {
-            value: "admin_header_menu",
-            name: log::blue("admin_header_menu - 基础后台管理平台(顶部菜单版)的模版"),
+            value: "lib",
+            name: log::cyan("lib - React组件库模板"),
+        },
+        SelectOption {
+            value: "mf_host",
+            name: log::yellow("mf_host - 微前端主应用(基于admin模板)"),
+        },
+        SelectOption {
+            value: "mf_remote",
+            name: log::blue("mf_remote - 微前端子应用(基于antd模板)"),
         },
     ];
-    let template = match template {
-        Some(value) => match templates.iter().position(|tmp| tmp.value == value) {
-            Some(pos) => pos as i8,
-            None => -1,
+    get_select_value_or_arg(templates, "模板", template)
+}
+
+fn get_layout_value(layout: Option<String>) -> &'static str {
+    let layouts = vec![
+        SelectOption {
+            value: "side",
+            name: log::cyan("侧边菜单"),
         },
-        None => -1,
-    };
+        SelectOption {
+            value: "header",
+            name: log::yellow("顶部菜单"),
+        },
+        SelectOption {
+            value: "mixed",
+            name: log::blue("顶部一级菜单 + 侧边子菜单"),
+        },
+    ];
+    get_select_value_or_arg(layouts, "后台布局", layout)
+}
 
-    if template == -1 {
-        // 选择模板
-        get_select_value(templates, "模板")
-    } else {
-        templates[template as usize].clone().value
-    }
+fn get_state_value() -> &'static str {
+    let states = vec![
+        SelectOption {
+            value: "zustand",
+            name: log::cyan("Zustand"),
+        },
+        SelectOption {
+            value: "redux",
+            name: log::yellow("Redux Toolkit"),
+        },
+        SelectOption {
+            value: "jotai",
+            name: log::blue("Jotai"),
+        },
+        SelectOption {
+            value: "none",
+            name: log::blue("无(基于useSyncExternalStore的简易实现)"),
+        },
+    ];
+    get_select_value(states, "状态管理库")
+}
+
+fn get_http_value() -> &'static str {
+    let https = vec![
+        SelectOption {
+            value: "axios",
+            name: log::cyan("Axios"),
+        },
+        SelectOption {
+            value: "fetch",
+            name: log::yellow("Fetch"),
+        },
+    ];
+    get_select_value(https, "请求库")
+}
+
+fn get_router_mode_value() -> &'static str {
+    let router_modes = vec![
+        SelectOption {
+            value: "hash",
+            name: log::cyan("Hash"),
+        },
+        SelectOption {
+            value: "browser",
+            name: log::yellow("History(需要服务端支持)"),
+        },
+    ];
+    get_select_value(router_modes, "路由模式")
+}
+
+fn get_theme_algorithm_value() -> &'static str {
+    let algorithms = vec![
+        SelectOption {
+            value: "default",
+            name: log::cyan("默认"),
+        },
+        SelectOption {
+            value: "compact",
+            name: log::yellow("紧凑"),
+        },
+        SelectOption {
+            value: "dark",
+            name: log::blue("暗色"),
+        },
+        SelectOption {
+            value: "dark,compact",
+            name: log::blue("暗色 + 紧凑"),
+        },
+    ];
+    get_select_value(algorithms, "主题算法")
+}
+
+fn get_package_manager_value() -> &'static str {
+    let package_managers = vec![
+        SelectOption {
+            value: "npm",
+            name: log::cyan("npm"),
+        },
+        SelectOption {
+            value: "yarn",
+            name: log::yellow("yarn"),
+        },
+        SelectOption {
+            value: "pnpm",
+            name: log::blue("pnpm"),
+        },
+    ];
+    get_select_value(package_managers, "包管理工具")
 }
 
 fn get_jts_loader_value() -> &'static str {
@@ -179,7 +610,57 @@ fn get_js_lint_value() -> &'static str {
     get_select_value(jts_loaders, "js格式化工具")
 }
 
-fn get_select_value(options: Vec<SelectOption>, prompt: &str) -> &str {
+fn get_test_value(test: Option<String>) -> &'static str {
+    let tests = vec![
+        SelectOption {
+            value: "vitest",
+            name: log::cyan("Vitest + Testing Library"),
+        },
+        SelectOption {
+            value: "jest",
+            name: log::yellow("Jest + Testing Library"),
+        },
+        SelectOption {
+            value: "",
+            name: log::blue("无"),
+        },
+    ];
+    get_select_value_or_arg(tests, "测试框架", test)
+}
+
+fn get_ci_value() -> &'static str {
+    let ci = vec![
+        SelectOption {
+            value: "github",
+            name: log::cyan("GitHub Actions"),
+        },
+        SelectOption {
+            value: "gitlab",
+            name: log::yellow("GitLab CI"),
+        },
+        SelectOption {
+            value: "",
+            name: log::blue("无"),
+        },
+    ];
+    get_select_value(ci, "CI流水线")
+}
+
+/// 命令行参数中的值有效时直接使用，否则进行选择
+fn get_select_value_or_arg(
+    options: Vec<SelectOption>,
+    prompt: &str,
+    arg: Option<String>,
+) -> &'static str {
+    let pos = arg.and_then(|value| options.iter().position(|tmp| tmp.value == value));
+
+    match pos {
+        Some(pos) => options[pos].value,
+        None => get_select_value(options, prompt),
+    }
+}
+
+fn get_select_value(options: Vec<SelectOption>, prompt: &str) -> &'static str {
     let names: Vec<String> = options.iter().map(|tmp| tmp.name.clone()).collect();
     let jts = Select::new()
         .with_prompt(log::yellow(prompt))
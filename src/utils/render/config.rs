@@ -1,8 +1,16 @@
-use crate::utils::{json::json_insert, prompts::Prompts};
+use super::deploy;
+use crate::utils::{generate::source, json::json_insert, pkg, prompts::Prompts};
 use serde_json::{self, json};
 use std::fs;
 use std::path::Path;
 
+/// Mock接口服务的地址，与mock/server.js中的端口保持一致
+const MOCK_URL: &str = "http://localhost:3001";
+/// 微前端子应用开发服务器的端口，与主应用src/remotes.ts中示例子应用的地址保持一致
+const REMOTE_PORT: u16 = 8081;
+/// 组件库演示页面的打包目录，dist目录用于组件库的打包产物
+const PLAYGROUND_DIST: &str = "playground-dist";
+
 pub fn rzpack_config(options: &Prompts) {
     let is_ts_template = options.template == "react_ts";
     let has_jst_loader = !options.jts_loader.is_empty();
@@ -18,7 +26,7 @@ pub fn rzpack_config(options: &Prompts) {
 
     let mut config = json!({
       "html": {
-        "title": options.project_name
+        "title": options.app_title
       },
     });
 
@@ -26,6 +34,17 @@ pub fn rzpack_config(options: &Prompts) {
         json_insert(&mut config, "assets", assets);
     }
 
+    // history模式的路由刷新页面时需要开发服务器返回index.html
+    if options.router_mode == "browser" {
+        json_insert(
+            &mut config,
+            "server",
+            json!({
+              "historyApiFallback": true,
+            }),
+        );
+    }
+
     if !is_ts_template {
         json_insert(
             &mut config,
@@ -43,10 +62,48 @@ pub fn rzpack_config(options: &Prompts) {
         );
     }
 
+    if options.template == "lib" {
+        json_insert(
+            &mut config,
+            "output",
+            json!({
+              "path": PLAYGROUND_DIST,
+            }),
+        );
+    }
+
+    if !options.federation.is_empty() {
+        json_insert(
+            &mut config,
+            "moduleFederation",
+            get_federation_config(options),
+        );
+    }
+
+    // 子应用的remoteEntry.js由主应用跨域加载
+    if options.federation == "remote" {
+        json_insert(
+            &mut config,
+            "server",
+            json!({
+              "port": REMOTE_PORT,
+              "headers": {
+                "Access-Control-Allow-Origin": "*",
+              },
+            }),
+        );
+    }
+
     let config_string = serde_json::to_string_pretty(&config).expect("Serialization failed");
     let mut content = String::from("import { defineConfig")
         + if has_jst_loader { ", JSX_TOOLS" } else { "" }
-        + "} from 'rzpack'\n\n"
+        + "} from 'rzpack'\n"
+        + if options.federation == "host" {
+            "\nimport { getFederationRemotes } from './src/remotes'\n"
+        } else {
+            ""
+        }
+        + "\n"
         + "export default defineConfig(\n"
         + config_string.as_str()
         + ")\n";
@@ -57,13 +114,240 @@ pub fn rzpack_config(options: &Prompts) {
             .replace("{}\"", options.jts_loader.to_uppercase().as_str());
     }
 
+    if options.federation == "host" {
+        content = content.replace("\"FEDERATION_REMOTES\"", "getFederationRemotes()");
+    }
+
     let path = options.root.join("rzpack.config.ts");
     fs::write(path, content).unwrap();
 }
 
-pub fn gitignore(root: &Path) {
-    let path = root.join(".gitignore");
-    fs::write(path, "node_modules\nbin\n*.log\n.vscode\n.DS_Store\ndist").unwrap();
+/// moduleFederation的name需要是合法的js标识符
+fn get_federation_name(package_name: &str) -> String {
+    let name: String = package_name
+        .trim_start_matches('@')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+/// 主应用和子应用共享的依赖，版本与package.json中的保持一致
+fn get_federation_shared(options: &Prompts) -> serde_json::Value {
+    let content = fs::read_to_string(options.root.join("package.json")).unwrap();
+    let pkgs: serde_json::Value =
+        serde_json::from_str(&content).expect("Failed to parse package.json");
+    let mut shared = json!({});
+
+    for name in ["react", "react-dom", "antd"] {
+        if let Some(version) = pkgs["dependencies"][name].as_str() {
+            json_insert(
+                &mut shared,
+                name,
+                json!({
+                  "singleton": true,
+                  "requiredVersion": version,
+                }),
+            );
+        }
+    }
+
+    shared
+}
+
+fn get_federation_config(options: &Prompts) -> serde_json::Value {
+    let mut config = json!({
+      "name": get_federation_name(&options.package_name),
+      "shared": get_federation_shared(options),
+    });
+
+    if options.federation == "host" {
+        // 由src/remotes.ts中的子应用注册表生成
+        json_insert(&mut config, "remotes", json!("FEDERATION_REMOTES"));
+    } else {
+        json_insert(&mut config, "filename", json!("remoteEntry.js"));
+        json_insert(
+            &mut config,
+            "exposes",
+            json!({
+              "./App": "./src/App",
+            }),
+        );
+    }
+
+    config
+}
+
+/// antd的主题配置，默认导出的token同时会被rzpack转换为less变量
+pub fn theme(options: &Prompts) {
+    // 开启暗色模式切换时，暗色模式下的文字颜色由AntdConfigProvider交给暗色算法生成
+    let is_dark = !options.dark_mode
+        && options
+            .theme_algorithm
+            .split(',')
+            .any(|item| item == "dark");
+    let algorithms: Vec<String> = options
+        .theme_algorithm
+        .split(',')
+        .map(|item| format!("'{}'", item))
+        .collect();
+
+    let mut tokens = vec![
+        ("borderRadius", options.border_radius.to_string()),
+        ("colorError", String::from("'#ff4d4f'")),
+        ("colorPrimary", format!("'{}'", options.primary_color)),
+        ("colorSuccess", String::from("'#00c48c'")),
+    ];
+    // 暗色主题下文字颜色需要使用浅色
+    let text_colors = if is_dark {
+        [
+            "'rgba(255, 255, 255, 0.85)'",
+            "'rgba(255, 255, 255, 0.25)'",
+            "'rgba(255, 255, 255, 0.65)'",
+        ]
+    } else {
+        ["'#3c4761'", "'#c5cee0'", "'#7487a3'"]
+    };
+    tokens.push(("colorText", text_colors[0].to_string()));
+    if options.template == "admin" {
+        tokens.push(("colorTextDisabled", text_colors[1].to_string()));
+        tokens.push(("colorTextSecondary", text_colors[2].to_string()));
+    }
+    tokens.push(("colorWarning", String::from("'#ffc245'")));
+
+    let mut content = String::from(
+        "export type ThemeAlgorithm = 'compact' | 'dark' | 'default'\n\n",
+    ) + "// 主题算法，在AntdConfigProvider中转换为antd的算法，可以组合使用\n"
+        + format!(
+            "export const algorithms: ThemeAlgorithm[] = [{}]\n\n",
+            algorithms.join(", ")
+        )
+        .as_str()
+        + "// 变量地址参考: https://ant-design.antgroup.com/docs/react/customize-theme-cn#theme\n"
+        + "export default {\n";
+    for (key, value) in tokens {
+        content += format!("  {}: {},\n", key, value).as_str();
+    }
+    content += "}\n";
+
+    let path = options.root.join("src/theme/index.ts");
+    fs::write(path, content).unwrap();
+
+    if options.template == "admin" {
+        global_vars(options, is_dark);
+    }
+}
+
+/// admin模板中布局使用的less变量，开启暗色模式切换时引用variables.less中的css变量
+fn global_vars(options: &Prompts, is_dark: bool) {
+    let vars = [
+        (
+            "colorBgHeader",
+            "#fff",
+            "#141414",
+            "--rzpack-color-bg-header",
+        ),
+        (
+            "colorBgLayout",
+            "#f6f9fb",
+            "#000",
+            "--rzpack-color-bg-layout",
+        ),
+        (
+            "colorBgSider",
+            "rgba(255, 255, 255, 0.5)",
+            "rgba(20, 20, 20, 0.5)",
+            "--rzpack-color-bg-sider",
+        ),
+        (
+            "colorBorderSecondary",
+            "#e4e9f2",
+            "#303030",
+            "--rzpack-color-border-secondary",
+        ),
+    ];
+
+    let mut content = String::from("// 全局less变量，在less文件中通过@xxx使用\n");
+    if options.dark_mode {
+        content += "// 值为css变量，亮色和暗色模式下的颜色见src/theme/variables.less\n";
+    }
+    content += "export default {\n";
+    for (key, light, dark, css_var) in vars {
+        let value = if options.dark_mode {
+            format!("var({})", css_var)
+        } else if is_dark {
+            dark.to_string()
+        } else {
+            light.to_string()
+        };
+        content += format!("  {}: '{}',\n", key, value).as_str();
+    }
+    content += "}\n";
+
+    let path = options.root.join("src/theme/globalVars.ts");
+    fs::write(path, content).unwrap();
+}
+
+pub fn gitignore(options: &Prompts) {
+    let mut content = String::from("node_modules\nbin\n*.log\n.vscode\n.DS_Store\ndist");
+    // 本地环境变量可能包含私密信息，不提交到仓库
+    content += "\n.env.local\n.env.*.local";
+
+    if options.template == "lib" {
+        content += "\n";
+        content += PLAYGROUND_DIST;
+    }
+
+    if options.e2e {
+        content += "\n/test-results/\n/playwright-report/\n/blob-report/\n/playwright/.cache/";
+    }
+
+    let path = options.root.join(".gitignore");
+    fs::write(path, content).unwrap();
+}
+
+pub fn env(options: &Prompts) {
+    let api_base_url = format!("RZPACK_API_BASE_URL={}\n", options.api_base_url);
+
+    let envs = [
+        (".env", "# 所有环境共用的变量，变量名需要以RZPACK_开头\n"),
+        (
+            ".env.development",
+            "# 开发环境(rzpack)的变量，会覆盖.env中的同名变量\n",
+        ),
+        (
+            ".env.production",
+            "# 生产环境(rzpack build)的变量，会覆盖.env中的同名变量\n",
+        ),
+    ];
+
+    for (filename, comment) in envs {
+        let api_base_url = if options.mock && filename == ".env.development" {
+            // 开发环境请求Mock接口服务，见mock/server.js
+            format!(
+                "# 接口请求的基础地址(Mock接口服务)\nRZPACK_API_BASE_URL={}\n",
+                MOCK_URL
+            )
+        } else {
+            String::from("# 接口请求的基础地址\n") + api_base_url.as_str()
+        };
+        let content = String::from(comment) + api_base_url.as_str();
+        fs::write(options.root.join(filename), content).unwrap();
+    }
+
+    let env_declaration = String::from("/// <reference types=\"rzpack/rzpack\" />\n\n")
+        + "declare namespace NodeJS {\n"
+        + "  interface ProcessEnv {\n"
+        + "    // 接口请求的基础地址\n"
+        + "    readonly RZPACK_API_BASE_URL: string\n"
+        + "  }\n"
+        + "}\n";
+    fs::write(options.root.join("rzpack-env.d.ts"), env_declaration).unwrap();
 }
 
 pub fn commit_lint_config(root: &Path) {
@@ -123,6 +407,416 @@ pub fn nodemon(root: &Path) {
     .unwrap();
 }
 
+pub fn ci(options: &Prompts) {
+    match options.ci.as_str() {
+        "github" => github_actions(options),
+        "gitlab" => gitlab_ci(options),
+        _ => {}
+    }
+}
+
+/// CI中需要依次执行的脚本，与`get_scripts`生成的脚本对应
+fn get_ci_scripts(options: &Prompts) -> Vec<&'static str> {
+    let mut scripts = vec![];
+
+    if !options.js_lint.is_empty() {
+        scripts.push("lint");
+    }
+
+    if options.style_lint {
+        scripts.push("lint:style");
+    }
+
+    if !options.test.is_empty() {
+        scripts.push("test");
+    }
+
+    scripts.push("build");
+    scripts
+}
+
+fn github_actions(options: &Prompts) {
+    let package_manager = options.package_manager.as_str();
+    let mut steps = String::from("      - uses: actions/checkout@v3\n");
+
+    if package_manager == "pnpm" {
+        steps = steps
+            + "      - uses: pnpm/action-setup@v2\n"
+            + "        with:\n"
+            + "          version: 8\n";
+    }
+
+    steps = steps
+        + "      - uses: actions/setup-node@v3\n"
+        + "        with:\n"
+        + "          node-version: 18\n"
+        + "          cache: "
+        + package_manager
+        + "\n"
+        + "      - run: "
+        + pkg::get_ci_install_command(package_manager).as_str()
+        + "\n";
+
+    for script in get_ci_scripts(options) {
+        steps =
+            steps + "      - run: " + pkg::get_run_command(package_manager, script).as_str() + "\n";
+    }
+
+    if options.e2e {
+        steps = steps
+            + "      - run: npx playwright install --with-deps chromium\n"
+            + "      - run: "
+            + pkg::get_run_command(package_manager, "test:e2e").as_str()
+            + "\n";
+    }
+
+    let content = String::from("name: CI\n\n")
+        + "on:\n"
+        + "  push:\n"
+        + "    branches: [main, master]\n"
+        + "  pull_request:\n\n"
+        + "jobs:\n"
+        + "  ci:\n"
+        + "    runs-on: ubuntu-latest\n"
+        + "    steps:\n"
+        + steps.as_str();
+
+    let dir = options.root.join(".github/workflows");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("ci.yml"), content).unwrap();
+}
+
+fn gitlab_ci(options: &Prompts) {
+    let package_manager = options.package_manager.as_str();
+    let run = |script: &str| pkg::get_run_command(package_manager, script);
+    let scripts = get_ci_scripts(options);
+
+    let (lock_file, cache_path, setup) = match package_manager {
+        "pnpm" => (
+            "pnpm-lock.yaml",
+            ".pnpm-store/",
+            "    - corepack enable\n    - pnpm config set store-dir .pnpm-store\n",
+        ),
+        "yarn" => ("yarn.lock", "node_modules/", ""),
+        _ => ("package-lock.json", "node_modules/", ""),
+    };
+
+    let mut content = String::from("image: node:18\n\n")
+        + "stages:\n"
+        + "  - lint\n"
+        + "  - test\n"
+        + "  - build\n\n"
+        + "cache:\n"
+        + "  key:\n"
+        + "    files:\n"
+        + "      - "
+        + lock_file
+        + "\n"
+        + "  paths:\n"
+        + "    - "
+        + cache_path
+        + "\n\n"
+        + "default:\n"
+        + "  before_script:\n"
+        + setup
+        + "    - "
+        + pkg::get_ci_install_command(package_manager).as_str()
+        + "\n";
+
+    let lint_scripts: Vec<&&str> = scripts.iter().filter(|s| s.starts_with("lint")).collect();
+    if !lint_scripts.is_empty() {
+        content = content + "\nlint:\n" + "  stage: lint\n" + "  script:\n";
+        for script in lint_scripts {
+            content = content + "    - " + run(script).as_str() + "\n";
+        }
+    }
+
+    if scripts.contains(&"test") {
+        content = content
+            + "\ntest:\n"
+            + "  stage: test\n"
+            + "  script:\n"
+            + "    - "
+            + run("test").as_str()
+            + "\n";
+    }
+
+    if options.e2e {
+        content = content
+            + "\ne2e:\n"
+            + "  stage: test\n"
+            + "  image: mcr.microsoft.com/playwright:v1.37.1-jammy\n"
+            + "  script:\n"
+            + "    - "
+            + run("test:e2e").as_str()
+            + "\n"
+            + "  artifacts:\n"
+            + "    when: on_failure\n"
+            + "    paths:\n"
+            + "      - playwright-report/\n";
+    }
+
+    content = content
+        + "\nbuild:\n"
+        + "  stage: build\n"
+        + "  script:\n"
+        + "    - "
+        + run("build").as_str()
+        + "\n"
+        + "  artifacts:\n"
+        + "    paths:\n"
+        + "      - dist/\n";
+
+    let path = options.root.join(".gitlab-ci.yml");
+    fs::write(path, content).unwrap();
+}
+
+/// admin模板中包裹App的Provider
+struct AppProvider {
+    // 第三方包的导入语句
+    package_import: &'static str,
+    // 项目内的导入语句
+    local_import: &'static str,
+    open_tag: &'static str,
+    name: &'static str,
+}
+
+/// 获取admin模板中包裹App的Provider，按从外到内的顺序排列
+fn get_app_providers(options: &Prompts) -> Vec<AppProvider> {
+    let mut providers = vec![];
+
+    if options.react_query {
+        providers.push(AppProvider {
+            package_import: "import { QueryClientProvider } from '@tanstack/react-query'\n",
+            local_import: "import queryClient from '@/utils/queryClient'\n",
+            open_tag: "<QueryClientProvider client={queryClient}>",
+            name: "QueryClientProvider",
+        });
+    }
+
+    // redux需要通过Provider提供store
+    if options.state == "redux" {
+        providers.push(AppProvider {
+            package_import: "import { Provider } from 'react-redux'\n",
+            local_import: "import store from '@/stores'\n",
+            open_tag: "<Provider store={store}>",
+            name: "Provider",
+        });
+    }
+
+    providers.push(AppProvider {
+        package_import: "",
+        local_import: "import { AntdConfigProvider } from '@/components'\n",
+        open_tag: "<AntdConfigProvider>",
+        name: "AntdConfigProvider",
+    });
+
+    providers
+}
+
+/// 使用Provider逐层包裹App，indent为最外层的缩进
+fn wrap_app(providers: &[AppProvider], indent: usize) -> String {
+    let mut content = String::new();
+    for (i, provider) in providers.iter().enumerate() {
+        content += format!("{}{}\n", " ".repeat(indent + i * 2), provider.open_tag).as_str();
+    }
+    content += format!("{}<App />\n", " ".repeat(indent + providers.len() * 2)).as_str();
+    for (i, provider) in providers.iter().enumerate().rev() {
+        content += format!("{}</{}>\n", " ".repeat(indent + i * 2), provider.name).as_str();
+    }
+
+    content
+}
+
+/// 获取Provider中第三方包的导入语句，导入语句按包名排序，scoped为true时获取排在react之前的@xxx包
+fn get_package_imports(providers: &[AppProvider], scoped: bool) -> String {
+    providers
+        .iter()
+        .filter(|provider| provider.package_import.contains("'@") == scoped)
+        .map(|provider| provider.package_import)
+        .collect()
+}
+
+/// 获取Provider中项目内的导入语句，按路径排序
+fn get_local_imports(providers: &[AppProvider]) -> String {
+    let mut imports: Vec<&str> = providers
+        .iter()
+        .map(|provider| provider.local_import)
+        .collect();
+    imports.sort_by_key(|item| item.split(" from ").last());
+
+    imports.concat()
+}
+
+/// admin模板的入口文件，需要根据选择的状态管理库等包裹Provider
+pub fn app_entry(options: &Prompts) {
+    let providers = get_app_providers(options);
+
+    let mut content = get_package_imports(&providers, true);
+    content += "import React from 'react'\n";
+    content += "import ReactDOM from 'react-dom/client'\n";
+    content += get_package_imports(&providers, false).as_str();
+    content += "\nimport App from '@/App'\n";
+    content += get_local_imports(&providers).as_str();
+
+    content = content
+        + "\nimport './app.less'\n\n"
+        + "const root = ReactDOM.createRoot(document.querySelector('#root') as HTMLDivElement)\n\n"
+        + "root.render(\n"
+        + wrap_app(&providers, 2).as_str()
+        + ")\n";
+
+    // 微前端主应用需要异步加载入口，保证共享依赖在使用前完成版本协商
+    if options.federation == "host" {
+        fs::write(options.root.join("src/bootstrap.tsx"), content).unwrap();
+        content = String::from(
+            "// 异步加载入口，保证共享依赖(react/antd)在使用前完成版本协商\nimport('./bootstrap')\n",
+        );
+    }
+
+    let path = options.root.join("src/main.tsx");
+    fs::write(path, content).unwrap();
+}
+
+/// 启动开发服务器的命令需要与选择的包管理工具一致，页面地址需要与路由模式一致
+pub fn playwright(options: &Prompts) {
+    let path = options.root.join("playwright.config.ts");
+    let content = fs::read_to_string(&path).unwrap().replace(
+        "command: 'npm run dev'",
+        format!(
+            "command: '{}'",
+            pkg::get_run_command(&options.package_manager, "dev")
+        )
+        .as_str(),
+    );
+    fs::write(path, content).unwrap();
+
+    if options.router_mode == "browser" {
+        let path = options.root.join("e2e/utils.ts");
+        let content = fs::read_to_string(&path)
+            .unwrap()
+            .replace("ROUTE_PREFIX = '/#'", "ROUTE_PREFIX = ''");
+        fs::write(path, content).unwrap();
+    }
+}
+
+/// 使用React Query时，菜单栏中的用户信息改为通过useUserInfoQuery获取，退出登录时清除缓存
+pub fn react_query(options: &Prompts) {
+    let path = options
+        .root
+        .join("src/layout/PageHeader/PageHeaderTools.tsx");
+    let content = fs::read_to_string(&path).unwrap();
+    let content = String::from("import { useQueryClient } from '@tanstack/react-query'\n")
+        + content
+            .replace(
+                "import { logout } from '@/api/system'\n",
+                "import { queryKeys, useUserInfoQuery } from '@/api/queries'\nimport { logout } from '@/api/system'\n",
+            )
+            .replace(
+                "  const [userInfo, clearUserInfo] = userInfoStore(state => [state.userInfo, state.clear])\n",
+                "  const queryClient = useQueryClient()\n  const { data: userInfo } = useUserInfoQuery()\n  const clearUserInfo = userInfoStore(state => state.clear)\n",
+            )
+            .replace(
+                "        clearUserInfo()\n",
+                "        clearUserInfo()\n        queryClient.removeQueries({ queryKey: queryKeys.userInfo })\n",
+            )
+            .as_str();
+    fs::write(path, content).unwrap();
+}
+
+/// 将应用标题渲染到登录页和菜单栏的logo中
+pub fn app_title(options: &Prompts) {
+    let title = source::escape_jsx_text(&options.app_title);
+    let files = [
+        ("src/pages/Login/index.tsx", "XXX管理系统"),
+        ("src/layout/PageHeader/PageHeaderLogo.tsx", "XXX管理平台"),
+    ];
+    for (file, placeholder) in files {
+        let path = options.root.join(file);
+        let content = fs::read_to_string(&path).unwrap();
+        fs::write(path, content.replace(placeholder, &title)).unwrap();
+    }
+}
+
+pub fn app_test(options: &Prompts) {
+    if options.template == "lib" {
+        lib_test(options);
+        return;
+    }
+
+    let is_ts_template = options.template == "react_ts";
+    let is_admin_template = options.template == "admin";
+    let providers = if is_admin_template {
+        get_app_providers(options)
+    } else {
+        vec![]
+    };
+
+    let mut imports = get_package_imports(&providers, true);
+    imports += "import { render, screen } from '@testing-library/react'\n";
+    // antd模板的示例需要模拟点击
+    if !is_ts_template && !is_admin_template {
+        imports += "import userEvent from '@testing-library/user-event'\n";
+    }
+    imports += "import React from 'react'\n";
+    imports += get_package_imports(&providers, false).as_str();
+    if options.test == "vitest" {
+        imports += "import { describe, expect, it } from 'vitest'\n";
+    }
+    imports += "\nimport App from '@/App'\n";
+    imports += get_local_imports(&providers).as_str();
+
+    let case = if is_ts_template {
+        String::from("  it('渲染欢迎语', () => {\n")
+            + "    render(<App />)\n"
+            + "    expect(screen.getByText('Hello Rzpack')).toBeInTheDocument()\n"
+            + "  })\n"
+    } else if is_admin_template {
+        String::from("  it('未登录时跳转到登录页', async () => {\n")
+            + "    render(\n"
+            + wrap_app(&providers, 6).as_str()
+            + "    )\n"
+            + format!(
+                "    expect(await screen.findByText('{}')).toBeInTheDocument()\n",
+                source::escape_js_string(&options.app_title)
+            )
+            .as_str()
+            + "  })\n"
+    } else {
+        String::from("  it('点击按钮更新计数', async () => {\n")
+            + "    const user = userEvent.setup()\n"
+            + "    render(<App />)\n"
+            + "    await user.click(screen.getByRole('button', { name: '+' }))\n"
+            + "    expect(screen.getByText('1')).toBeInTheDocument()\n"
+            + "  })\n"
+    };
+
+    let content = imports + "\ndescribe('App', () => {\n" + case.as_str() + "})\n";
+
+    let path = options.root.join("src/App.test.tsx");
+    fs::write(path, content).unwrap();
+}
+
+/// 组件库的用例直接测试导出的组件
+fn lib_test(options: &Prompts) {
+    let mut content = String::from("import { render, screen } from '@testing-library/react'\n")
+        + "import React from 'react'\n";
+    if options.test == "vitest" {
+        content += "import { describe, expect, it } from 'vitest'\n";
+    }
+    content = content
+        + "\nimport { StatusTag } from '..'\n"
+        + "\ndescribe('StatusTag', () => {\n"
+        + "  it('渲染标签内容', () => {\n"
+        + "    render(<StatusTag status=\"success\">成功</StatusTag>)\n"
+        + "    expect(screen.getByText('成功')).toBeInTheDocument()\n"
+        + "  })\n"
+        + "})\n";
+
+    let path = options.root.join("src/StatusTag/index.test.tsx");
+    fs::write(path, content).unwrap();
+}
+
 pub fn readme(options: &Prompts) {
     let mut plugin_info = String::from("\n");
     let eslint_plugin = "- `ESLint`\n- `Prettier - Code formatter`\n";
@@ -202,6 +896,107 @@ pub fn readme(options: &Prompts) {
         }
         + "\n";
 
+    let run = |script: &str| pkg::get_run_command(&options.package_manager, script);
+
+    let test_info = if options.test.is_empty() {
+        String::from("")
+    } else {
+        String::from("## 测试\n\n```bash\n") + run("test").as_str() + "\n```\n"
+    };
+
+    let e2e_info = if options.e2e {
+        String::from("## E2E测试\n\n首次运行前需要安装浏览器\n\n```bash\n")
+            + "npx playwright install\n"
+            + run("test:e2e").as_str()
+            + "\n```\n"
+    } else {
+        String::from("")
+    };
+
+    let mock_info = if options.mock {
+        String::from("## Mock接口\n\n")
+            + "`"
+            + run("dev").as_str()
+            + "`会同时启动`mock/server.js`中的Mock接口服务("
+            + MOCK_URL
+            + ")，"
+            + "`src/api`下请求函数中的接口会自动生成Mock接口，接口数据在`mock`目录下维护，"
+            + "没有Mock数据的接口返回空数据，菜单数据读取自`src/router/routes.ts`。"
+            + "对接真实接口时修改`.env.development`中的`RZPACK_API_BASE_URL`即可\n\n"
+    } else {
+        String::from("")
+    };
+
+    let rbac_info = if options.rbac {
+        String::from("## 权限控制\n\n")
+            + "- 在`src/router/routes.ts`的路由上配置`permissions`，用户拥有其中任意一个权限才能访问\n"
+            + "- 按钮级别的权限使用`<Auth permission=\"xxx\">`组件或`usePermission`控制\n"
+            + "- 用户的权限来自用户信息中的`permissions`，`*`表示拥有所有权限\n"
+            + "- 没有权限的路由不会被注册，也不会出现在菜单中\n"
+            + "- Mock数据中使用`guest`账号登录时没有任何权限，其他账号拥有所有权限\n\n"
+    } else {
+        String::from("")
+    };
+
+    let dark_mode_info = if options.dark_mode {
+        String::from("## 暗色模式\n\n")
+            + "- 顶部的切换按钮会将主题模式保存到`localStorage`，由`src/stores/theme.ts`维护\n"
+            + "- 布局相关的颜色在`src/theme/variables.less`中通过css变量分别配置亮色和暗色两套\n\n"
+    } else {
+        String::from("")
+    };
+
+    let federation_info = match options.federation.as_str() {
+        "host" => {
+            String::from("## 微前端\n\n")
+                + "- 子应用在`src/remotes.ts`中注册，`rzpack.config.ts`会根据注册表生成`moduleFederation.remotes`\n"
+                + "- 子应用的页面通过`lazy(() => import('sub_app/App'))`引入，使用`RemoteApp`组件渲染，见`src/pages/Micro/SubApp`\n"
+                + "- 新增子应用时需要在`src/remotes.d.ts`中添加暴露模块的类型声明\n\n"
+        }
+        "remote" => {
+            String::from("## 微前端\n\n")
+                + format!(
+                    "- 开发服务器的端口为{}，主应用通过`http://localhost:{}/remoteEntry.js`加载\n",
+                    REMOTE_PORT, REMOTE_PORT
+                )
+                .as_str()
+                + format!(
+                    "- 暴露的模块在`rzpack.config.ts`的`moduleFederation.exposes`中配置，主应用通过`{}/App`引入\n\n",
+                    get_federation_name(&options.package_name)
+                )
+                .as_str()
+        }
+        _ => String::from(""),
+    };
+
+    let lib_info = if options.template == "lib" {
+        String::from("## 发布\n\n")
+            + "`src/index.ts`中导出的组件会通过`tsup`打包到`dist`目录，"
+            + "`react`、`react-dom`和`antd`作为`peerDependencies`由使用方安装，"
+            + "演示页面(`src/App.tsx`)不会被打包，"
+            + "需要部署演示页面时执行`"
+            + run("build:playground").as_str()
+            + "`打包到`"
+            + PLAYGROUND_DIST
+            + "`目录\n\n"
+            + "```bash\n"
+            + run("build").as_str()
+            + "\nnpm publish\n"
+            + "```\n"
+    } else {
+        String::from("")
+    };
+
+    let docker_info = if options.docker {
+        let name = deploy::get_image_name(&options.package_name);
+        String::from("## 部署\n\n```bash\n")
+            + format!("docker build -t {} .\n", name).as_str()
+            + format!("docker run -d -p 8080:80 {}\n", name).as_str()
+            + "```\n"
+    } else {
+        String::from("")
+    };
+
     let content = String::from("")
         + "# "
         + options.project_name.as_str()
@@ -209,12 +1004,20 @@ pub fn readme(options: &Prompts) {
         + "> create-rzpack创建的React项目\n\n"
         + "## 开发\n\n"
         + "```bash\n"
-        + "npm run dev\n"
-        + "```\n"
+        + run("dev").as_str()
+        + "\n```\n"
+        + mock_info.as_str()
+        + rbac_info.as_str()
+        + dark_mode_info.as_str()
+        + federation_info.as_str()
         + "## 打包\n\n"
         + "```bash\n"
-        + "npm run build\n"
-        + "```\n"
+        + run("build").as_str()
+        + "\n```\n"
+        + test_info.as_str()
+        + e2e_info.as_str()
+        + lib_info.as_str()
+        + docker_info.as_str()
         + plugin_info.as_str();
 
     let path = options.root.join("README.md");
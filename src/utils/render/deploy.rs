@@ -0,0 +1,110 @@
+use crate::utils::{pkg, prompts::Prompts};
+use std::fs;
+
+pub fn create(options: &Prompts) {
+    let name = get_image_name(&options.package_name);
+
+    dockerfile(options, &name);
+    dockerignore(options);
+    nginx(options);
+}
+
+/// 镜像名中不能包含scope的`@`和`/`
+pub fn get_image_name(package_name: &str) -> String {
+    package_name.replace('@', "").replace('/', "-")
+}
+
+fn dockerfile(options: &Prompts, name: &str) {
+    let package_manager = options.package_manager.as_str();
+    let lock_file = match package_manager {
+        "pnpm" => "pnpm-lock.yaml",
+        "yarn" => "yarn.lock",
+        _ => "package-lock.json",
+    };
+    let setup = if package_manager == "pnpm" {
+        "RUN corepack enable\n"
+    } else {
+        ""
+    };
+
+    let content = String::from("# 构建阶段\n")
+        + "FROM node:18-alpine AS build\n"
+        + "WORKDIR /app\n"
+        + "# 镜像中没有git仓库，跳过git hooks的安装\n"
+        + "ENV SKIP_INSTALL_SIMPLE_GIT_HOOKS=1\n"
+        + setup
+        + "COPY package.json "
+        + lock_file
+        + "* ./\n"
+        + "# 还没有lock文件时(如创建后未安装依赖)按package.json安装\n"
+        + "RUN if [ -f "
+        + lock_file
+        + " ]; then "
+        + pkg::get_ci_install_command(package_manager).as_str()
+        + "; else "
+        + pkg::get_install_command(package_manager).as_str()
+        + "; fi\n"
+        + "COPY . .\n"
+        + "RUN "
+        + pkg::get_run_command(package_manager, "build").as_str()
+        + "\n\n"
+        + "# 运行阶段\n"
+        + "FROM nginx:1.25-alpine\n"
+        + "LABEL name=\""
+        + name
+        + "\"\n"
+        + "COPY nginx.conf /etc/nginx/conf.d/default.conf\n"
+        + "COPY --from=build /app/dist /usr/share/nginx/html\n"
+        + "EXPOSE 80\n"
+        + "CMD [\"nginx\", \"-g\", \"daemon off;\"]\n";
+
+    let path = options.root.join("Dockerfile");
+    fs::write(path, content).unwrap();
+}
+
+fn dockerignore(options: &Prompts) {
+    let content = String::from("node_modules\n")
+        + "dist\n"
+        + ".git\n"
+        + "*.log\n"
+        + ".DS_Store\n"
+        // 本地环境变量可能包含私密信息，不复制到镜像中
+        + ".env.local\n"
+        + ".env.*.local\n"
+        + "Dockerfile\n"
+        + ".dockerignore\n"
+        + "test-results\n"
+        + "playwright-report\n";
+
+    let path = options.root.join(".dockerignore");
+    fs::write(path, content).unwrap();
+}
+
+fn nginx(options: &Prompts) {
+    let content = String::from("server {\n")
+        + "    listen 80;\n"
+        + "    server_name localhost;\n"
+        + "    root /usr/share/nginx/html;\n"
+        + "    index index.html;\n\n"
+        // 日志输出到stdout/stderr，可以通过docker logs查看
+        + "    access_log /dev/stdout;\n"
+        + "    error_log /dev/stderr;\n\n"
+        + "    gzip on;\n"
+        + "    gzip_types text/plain text/css application/javascript application/json image/svg+xml;\n\n"
+        + "    location / {\n"
+        + "        # 前端路由的页面刷新时回退到index.html\n"
+        + "        try_files $uri $uri/ /index.html;\n"
+        + "    }\n\n"
+        + "    # index.html不缓存，保证发版后能加载到最新的资源\n"
+        + "    location = /index.html {\n"
+        + "        add_header Cache-Control \"no-cache\";\n"
+        + "    }\n\n"
+        + "    location ~* \\.(js|css|png|jpe?g|gif|svg|webp|woff2?)$ {\n"
+        + "        expires 30d;\n"
+        + "        add_header Cache-Control \"public\";\n"
+        + "    }\n"
+        + "}\n";
+
+    let path = options.root.join("nginx.conf");
+    fs::write(path, content).unwrap();
+}
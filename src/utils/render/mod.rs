@@ -1,3 +1,6 @@
 pub mod config;
+pub mod deploy;
+pub mod monorepo;
 pub mod package;
+pub mod rbac;
 pub mod template;
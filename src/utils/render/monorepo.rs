@@ -0,0 +1,317 @@
+use serde_json::{self, json, Value};
+use std::fs;
+use std::path::Path;
+
+use crate::utils::{
+    json::json_insert,
+    prompts::{MonorepoPrompts, Prompts},
+    render::{package, template},
+};
+
+/// 应用位于apps/<name>，共享包位于packages/<name>
+const PACKAGES_PATH: &str = "../../packages";
+
+/// 共享包的scope，如：@my-app/ui
+fn get_scope(options: &MonorepoPrompts) -> String {
+    let name = options.package_name.rsplit('/').next().unwrap_or_default();
+    format!("@{}", name)
+}
+
+fn write_json(path: &Path, value: &Value) {
+    let content = serde_json::to_string_pretty(value).expect("Serialization failed") + "\n";
+    fs::write(path, content).unwrap();
+}
+
+/// 创建monorepo根目录及packages下的共享包，应用由模板流程在apps下创建
+pub fn create(options: &MonorepoPrompts) {
+    let root = options.root.as_path();
+    template::copy(vec!["monorepo"], &[], root).unwrap();
+
+    let scope = get_scope(options);
+    let eslint_packages = package::get_js_lint_packages("eslint").unwrap();
+    let style_lint_packages = package::get_style_lint_packages();
+
+    let pkgs = json!({
+        "name": options.package_name,
+        "version": "0.0.1",
+        "private": true,
+        "scripts": {
+            "dev": "pnpm --parallel --filter \"./apps/*\" dev",
+            "build": "pnpm --filter \"./apps/*\" build",
+            "lint": "pnpm -r lint",
+            "lint:style": "stylelint \"{apps,packages}/*/src/**/*.{less,css}\"",
+            "prepare": "npx simple-git-hooks",
+        },
+        "simple-git-hooks": {
+            "pre-commit": "npx lint-staged",
+        },
+        "lint-staged": {
+            "{apps,packages}/*/src/**/*.{js,jsx,ts,tsx}": ["eslint --fix", "prettier --write"],
+            "{apps,packages}/*/src/**/*.{less,css}": ["stylelint --fix"],
+        },
+        "license": "MIT",
+        "devDependencies": {
+            format!("{}/eslint-config", scope): "workspace:*",
+            format!("{}/stylelint-config", scope): "workspace:*",
+            format!("{}/tsconfig", scope): "workspace:*",
+            "eslint": eslint_packages["eslint"],
+            "prettier": eslint_packages["prettier"],
+            "stylelint": style_lint_packages["stylelint"],
+            "typescript": "5.1.6",
+            "simple-git-hooks": "^2.9.0",
+            "lint-staged": "^13.2.3",
+        },
+    });
+    write_json(&root.join("package.json"), &pkgs);
+
+    let workspace = String::from("packages:\n") + "  - 'apps/*'\n" + "  - 'packages/*'\n";
+    fs::write(root.join("pnpm-workspace.yaml"), workspace).unwrap();
+
+    // 根目录的配置直接使用共享包，应用中不再生成
+    fs::write(
+        root.join(".eslintrc.js"),
+        format!(
+            "module.exports = {{\n  extends: '{}/eslint-config',\n}}\n",
+            scope
+        ),
+    )
+    .unwrap();
+    fs::write(
+        root.join(".stylelintrc.js"),
+        format!(
+            "module.exports = {{\n  extends: '{}/stylelint-config',\n}}\n",
+            scope
+        ),
+    )
+    .unwrap();
+    fs::write(
+        root.join(".gitignore"),
+        "node_modules\n*.log\n.vscode\n.DS_Store\ndist\n.env.local\n.env.*.local",
+    )
+    .unwrap();
+
+    write_json(
+        &root.join("packages/tsconfig/package.json"),
+        &json!({
+            "name": format!("{}/tsconfig", scope),
+            "version": "0.0.1",
+            "private": true,
+            "files": ["base.json"],
+        }),
+    );
+
+    let mut eslint_config_dependencies = json!({});
+    json_insert(
+        &mut eslint_config_dependencies,
+        "eslint-config-rzpack",
+        eslint_packages["eslint-config-rzpack"].clone(),
+    );
+    write_json(
+        &root.join("packages/eslint-config/package.json"),
+        &json!({
+            "name": format!("{}/eslint-config", scope),
+            "version": "0.0.1",
+            "private": true,
+            "main": "index.js",
+            "dependencies": eslint_config_dependencies,
+            "peerDependencies": {
+                "eslint": eslint_packages["eslint"],
+            },
+        }),
+    );
+
+    let mut style_lint_config_dependencies = style_lint_packages.clone();
+    if let Some(dependencies) = style_lint_config_dependencies.as_object_mut() {
+        dependencies.remove("stylelint");
+    }
+    write_json(
+        &root.join("packages/stylelint-config/package.json"),
+        &json!({
+            "name": format!("{}/stylelint-config", scope),
+            "version": "0.0.1",
+            "private": true,
+            "main": "index.js",
+            "dependencies": style_lint_config_dependencies,
+            "peerDependencies": {
+                "stylelint": style_lint_packages["stylelint"],
+            },
+        }),
+    );
+
+    // 直接引用源码，由应用的rzpack编译
+    write_json(
+        &root.join("packages/ui/package.json"),
+        &json!({
+            "name": format!("{}/ui", scope),
+            "version": "0.0.1",
+            "private": true,
+            "main": "src/index.ts",
+            "types": "src/index.ts",
+            "scripts": {
+                "lint": "eslint src --ext .js,.jsx,.ts,.tsx",
+            },
+            "peerDependencies": {
+                "react": "^18.2.0",
+            },
+            "devDependencies": {
+                format!("{}/tsconfig", scope): "workspace:*",
+                "@types/react": "^18.0.25",
+                "react": "^18.2.0",
+            },
+        }),
+    );
+    // 被应用通过references引用的项目需要开启composite并输出类型声明
+    write_json(
+        &root.join("packages/ui/tsconfig.json"),
+        &json!({
+            "extends": format!("{}/tsconfig/base.json", scope),
+            "compilerOptions": {
+                "composite": true,
+                "noEmit": false,
+                "emitDeclarationOnly": true,
+                "outDir": "dist",
+                "rootDir": "src",
+            },
+            "include": ["src"],
+        }),
+    );
+
+    readme(options);
+}
+
+/// 应用通过workspace依赖和tsconfig的paths/references使用共享包
+pub fn link_app(options: &MonorepoPrompts, app: &Prompts) {
+    let scope = get_scope(options);
+    let ui = format!("{}/ui", scope);
+
+    let path = app.root.join("package.json");
+    let content = fs::read_to_string(&path).unwrap();
+    let mut pkgs: Value = serde_json::from_str(&content).expect("Failed to parse package.json");
+    // 组件库会被发布，不发布的共享包作为开发依赖由tsup打包到产物中
+    let ui_field = if app.template == "lib" {
+        "devDependencies"
+    } else {
+        "dependencies"
+    };
+    // 没有运行时依赖的应用(如组件库)不会生成dependencies
+    for field in [ui_field, "devDependencies"] {
+        if !pkgs[field].is_object() {
+            pkgs[field] = json!({});
+        }
+    }
+    json_insert(&mut pkgs[ui_field], &ui, json!("workspace:*"));
+    json_insert(
+        &mut pkgs["devDependencies"],
+        format!("{}/tsconfig", scope).as_str(),
+        json!("workspace:*"),
+    );
+    write_json(&path, &pkgs);
+
+    let mut paths = json!({
+        "@/*": ["./src/*"],
+    });
+    json_insert(
+        &mut paths,
+        &ui,
+        json!([format!("{}/ui/src", PACKAGES_PATH)]),
+    );
+    write_json(
+        &app.root.join("tsconfig.json"),
+        &json!({
+            "extends": format!("{}/tsconfig/base.json", scope),
+            "compilerOptions": {
+                "baseUrl": ".",
+                "paths": paths,
+            },
+            "references": [{ "path": format!("{}/ui", PACKAGES_PATH) }],
+        }),
+    );
+}
+
+fn readme(options: &MonorepoPrompts) {
+    let scope = get_scope(options);
+    let apps = options
+        .apps
+        .iter()
+        .enumerate()
+        .map(|(i, app)| {
+            let prefix = if i == options.apps.len() - 1 {
+                "└──"
+            } else {
+                "├──"
+            };
+            format!("│   {} {}\n", prefix, app)
+        })
+        .collect::<String>();
+
+    let content = String::from("# ")
+        + options.project_name.as_str()
+        + "\n\n"
+        + "> create-rzpack创建的pnpm monorepo\n\n"
+        + "## 目录结构\n\n"
+        + "```\n"
+        + "├── apps\n"
+        + apps.as_str()
+        + "└── packages\n"
+        + format!("    ├── eslint-config     # {}/eslint-config\n", scope).as_str()
+        + format!("    ├── stylelint-config  # {}/stylelint-config\n", scope).as_str()
+        + format!("    ├── tsconfig          # {}/tsconfig\n", scope).as_str()
+        + format!("    └── ui                # {}/ui\n", scope).as_str()
+        + "```\n\n"
+        + "## 开发\n\n"
+        + "```bash\n"
+        + "pnpm install\n"
+        + "pnpm dev\n"
+        + "```\n\n"
+        + "单独启动某个应用：`pnpm --filter <应用名称> dev`\n\n"
+        + "## 打包\n\n"
+        + "```bash\n"
+        + "pnpm build\n"
+        + "```\n";
+
+    fs::write(options.root.join("README.md"), content).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn link_lib_without_runtime_dependency() {
+        let root = env::temp_dir().join(format!("create-rzpack-link-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name":"ui-kit","devDependencies":{"tsup":"^7.2.0"},"peerDependencies":{"react":"^18.2.0"}}"#,
+        )
+        .unwrap();
+        let options = MonorepoPrompts {
+            package_name: String::from("my-app"),
+            ..Default::default()
+        };
+        let app = Prompts {
+            template: String::from("lib"),
+            root: root.clone(),
+            ..Default::default()
+        };
+
+        link_app(&options, &app);
+
+        let content = fs::read_to_string(root.join("package.json")).unwrap();
+        let pkgs: Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(pkgs.get("dependencies"), None);
+        assert_eq!(
+            pkgs["devDependencies"],
+            json!({
+              "@my-app/tsconfig": "workspace:*",
+              "@my-app/ui": "workspace:*",
+              "tsup": "^7.2.0",
+            })
+        );
+        assert_eq!(pkgs["peerDependencies"], json!({ "react": "^18.2.0" }));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,33 +1,22 @@
-use serde::{Deserialize, Serialize};
 use serde_json::{self, json, Value};
-use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
+use std::time::Duration;
 
 use crate::utils::{
     json::{json_insert, json_merge},
+    log, pkg,
     prompts::Prompts,
     tools,
 };
 
-#[derive(Serialize, Deserialize)]
-struct Packages {
-    name: String,
-    version: String,
-    scripts: HashMap<&'static str, &'static str>,
-    browserslist: [&'static str; 4],
-    simple_git_hooks: HashMap<&'static str, &'static str>,
-    license: &'static str,
-    lint_staged: Option<HashMap<String, Vec<String>>>,
-    config: HashMap<&'static str, HashMap<&'static str, &'static str>>,
-    dependencies: HashMap<String, String>,
-    dev_dependencies: HashMap<String, String>,
-}
+/// 获取最新版本的超时时间
+const NPM_VIEW_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub async fn create(options: &Prompts) {
-    let scripts = get_scripts(options.rs, options.commit_lint);
+    let scripts = get_scripts(options);
     let simple_git_hooks = get_simple_git_hooks(options.commit_lint);
-    let lint_staged = get_lint_staged_scripts(&options.js_lint, options.style_lint);
+    let lint_staged = get_lint_staged_scripts(&options.js_lint, options.style_lint, &options.test);
     let commit_config = json!( {
       "commitizen": {
         "path":"node_modules/cz-customizable",
@@ -36,31 +25,44 @@ pub async fn create(options: &Prompts) {
         "config": "cz.config.js",
       },
     });
-    let dependencies = get_dependencies(&options.template);
+    let dependencies = get_dependencies(options);
 
-    let dev_dependencies = get_dev_dependencies(
-        &options.template,
-        options.commit_lint,
-        &options.js_lint,
-        options.style_lint,
-    );
+    let dev_dependencies = get_dev_dependencies(options);
 
     let mut pkgs = json!({
         "name": options.package_name,
         "version": "0.0.1",
         "scripts":scripts,
         "browserslist": [">0.2%", "not dead", "not IE 11", "not op_mini all"],
-        "simple-git-hooks":simple_git_hooks,
-        "lint-staged":lint_staged,
         "license": "MIT",
-        "dependencies": dependencies,
         "devDependencies":dev_dependencies,
     });
 
+    if dependencies
+        .as_object()
+        .is_some_and(|value| !value.is_empty())
+    {
+        json_insert(&mut pkgs, "dependencies", dependencies);
+    }
+
+    // 工作区中的git钩子由根目录统一管理
+    if options.workspace.is_none() {
+        json_insert(&mut pkgs, "simple-git-hooks", simple_git_hooks);
+        json_insert(&mut pkgs, "lint-staged", json!(lint_staged));
+    }
+
     if options.commit_lint {
         json_insert(&mut pkgs, "config", commit_config);
     }
 
+    if let Some(peer_dependencies) = get_peer_dependencies(options) {
+        json_insert(&mut pkgs, "peerDependencies", peer_dependencies);
+    }
+
+    if options.template == "lib" {
+        pkgs = json_merge(pkgs, get_publish_fields());
+    }
+
     let json_string = serde_json::to_string_pretty(&pkgs).expect("Serialization failed");
     let path = options.root.join("package.json");
 
@@ -69,26 +71,93 @@ pub async fn create(options: &Prompts) {
         .expect("Write failed");
 }
 
-fn get_scripts(rs: bool, commit_lint: bool) -> Value {
+fn get_scripts(options: &Prompts) -> Value {
     let mut scripts = json!( {
      "dev":"rzpack",
      "build": "rzpack build",
      "build:time": "rzpack build --bundle-time",
      "build:size": "rzpack build --bundle-size",
      "preview": "rzpack preview",
-     "prepare": "npx simple-git-hooks",
     });
 
-    if rs {
+    if options.workspace.is_none() {
+        json_insert(&mut scripts, "prepare", json!("npx simple-git-hooks"));
+    }
+
+    // 组件库使用tsup打包，rzpack只用于启动和打包演示页面
+    if options.template == "lib" {
+        if let Some(scripts) = scripts.as_object_mut() {
+            scripts.remove("build:time");
+            scripts.remove("build:size");
+        }
+        json_insert(&mut scripts, "build", json!("tsup"));
+        json_insert(&mut scripts, "build:playground", json!("rzpack build"));
+        json_insert(
+            &mut scripts,
+            "prepublishOnly",
+            json!(pkg::get_run_command(&options.package_manager, "build")),
+        );
+    }
+
+    if options.rs {
         json_insert(&mut scripts, "dev:rs", json!("nodemon"));
     }
 
-    if commit_lint {
+    if options.mock {
+        // 同时启动Mock接口服务和开发服务器
+        json_insert(
+            &mut scripts,
+            "dev",
+            json!("concurrently -k -n mock,app -c blue,green \"node mock/server.js\" rzpack"),
+        );
+        json_insert(&mut scripts, "mock", json!("node mock/server.js"));
+    }
+
+    match options.js_lint.as_str() {
+        "" => {}
+        "rome" => json_insert(&mut scripts, "lint", json!("rome check src")),
+        _ => json_insert(
+            &mut scripts,
+            "lint",
+            json!("eslint src --ext .js,.jsx,.ts,.tsx"),
+        ),
+    }
+
+    if options.style_lint {
+        json_insert(
+            &mut scripts,
+            "lint:style",
+            json!("stylelint \"src/**/*.{less,css}\""),
+        );
+    }
+
+    if options.commit_lint {
         json_insert(&mut scripts, "cz", json!("git-cz"));
         json_insert(&mut scripts, "release", json!("standard-version"));
     }
 
-    return scripts;
+    match options.test.as_str() {
+        "vitest" => {
+            json_insert(&mut scripts, "test", json!("vitest run"));
+            json_insert(&mut scripts, "test:watch", json!("vitest"));
+        }
+        "jest" => {
+            json_insert(&mut scripts, "test", json!("jest"));
+            json_insert(&mut scripts, "test:watch", json!("jest --watch"));
+        }
+        _ => {}
+    }
+
+    if options.e2e {
+        json_insert(&mut scripts, "test:e2e", json!("playwright test"));
+        json_insert(
+            &mut scripts,
+            "test:e2e:report",
+            json!("playwright show-report"),
+        );
+    }
+
+    scripts
 }
 
 fn get_simple_git_hooks(commit_lint: bool) -> Value {
@@ -104,77 +173,153 @@ fn get_simple_git_hooks(commit_lint: bool) -> Value {
         );
     }
 
-    return hooks;
+    hooks
 }
 
-fn get_lint_staged_scripts(js_lint: &String, style_lint: bool) -> Option<Value> {
-    let lint_staged_scripts: Option<Value> = if !js_lint.is_empty() {
-        Some(json!({
-          "src/**/*.{js,jsx,ts,tsx}":
-            if js_lint == "rome" { vec!["rome check".to_string(), "rome format --write".to_string()]} else {vec!["eslint --fix".to_string(), "prettier --write".to_string()]},
-        }))
-    } else {
-        None
+fn get_lint_staged_scripts(js_lint: &str, style_lint: bool, test: &str) -> Option<Value> {
+    let mut js_scripts: Vec<String> = match js_lint {
+        "" => vec![],
+        "rome" => vec!["rome check".to_string(), "rome format --write".to_string()],
+        _ => vec!["eslint --fix".to_string(), "prettier --write".to_string()],
     };
 
+    // 提交前只运行与改动文件相关的测试
+    match test {
+        "vitest" => js_scripts.push("vitest related --run".to_string()),
+        "jest" => js_scripts.push("jest --bail --findRelatedTests --passWithNoTests".to_string()),
+        _ => {}
+    }
+
+    let mut lint_staged_scripts = json!({});
+    if !js_scripts.is_empty() {
+        json_insert(
+            &mut lint_staged_scripts,
+            "src/**/*.{js,jsx,ts,tsx}",
+            json!(js_scripts),
+        );
+    }
+
     if style_lint {
-        match lint_staged_scripts {
-            Some(mut value) => {
-                json_insert(
-                    &mut value,
-                    "src/**/*.{less,css}",
-                    json!(vec!["stylelint --fix".to_string()]),
-                );
-                Some(value)
-            }
-            None => Some(json!({"src/**/*.{less,css}":vec!["stylelint --fix".to_string()]})),
-        }
+        json_insert(
+            &mut lint_staged_scripts,
+            "src/**/*.{less,css}",
+            json!(vec!["stylelint --fix".to_string()]),
+        );
+    }
+
+    if js_scripts.is_empty() && !style_lint {
+        None
+    } else {
+        Some(lint_staged_scripts)
+    }
+}
+
+/// 组件库发布到npm需要的字段，与tsup的产物对应
+fn get_publish_fields() -> Value {
+    // exports中不写types条件(序列化后无法保证排在第一位)，
+    // tsup会为每种格式生成同名的类型声明(index.d.ts/index.d.mts)
+    json!({
+        "main": "./dist/index.js",
+        "module": "./dist/index.mjs",
+        "types": "./dist/index.d.ts",
+        "exports": {
+            ".": {
+                "import": "./dist/index.mjs",
+                "require": "./dist/index.js",
+            },
+        },
+        "files": ["dist"],
+        "sideEffects": false,
+    })
+}
+
+/// 组件库中的react/antd由使用方提供
+fn get_peer_dependencies(options: &Prompts) -> Option<Value> {
+    if options.template == "lib" {
+        Some(json!({
+          "antd": "^5.8.1",
+          "react": "^18.2.0",
+          "react-dom": "^18.2.0",
+        }))
     } else {
         None
     }
 }
 
-fn get_dependencies(template: &String) -> Value {
-    let is_admin_template = ["admin", "admin_header_menu"].contains(&template.as_str());
+fn get_dependencies(options: &Prompts) -> Value {
+    // 组件库没有运行时依赖，react/antd在peerDependencies中
+    if options.template == "lib" {
+        return json!({});
+    }
+
+    let is_admin_template = options.template == "admin";
     let mut dependencies = json!({
       "react":"^18.2.0",
       "react-dom":"^18.2.0",
       "dayjs": "^1.11.9",
     });
 
-    if template != "react_ts" {
+    if options.template != "react_ts" {
         json_insert(&mut dependencies, "@ant-design/icons", json!("^5.2.4"));
         json_insert(&mut dependencies, "antd", json!("^5.8.1"));
     }
 
     if is_admin_template {
         json_insert(&mut dependencies, "@renzp/storage", json!("^0.0.1"));
-        json_insert(&mut dependencies, "axios", json!("^1.4.0"));
         json_insert(&mut dependencies, "lodash-es", json!("^4.17.21"));
         json_insert(&mut dependencies, "nprogress", json!("^0.2.0"));
         json_insert(&mut dependencies, "react-router-dom", json!("^6.14.2"));
-        json_insert(&mut dependencies, "zustand", json!("^4.4.0"));
     }
 
-    return dependencies;
+    if options.http == "axios" {
+        json_insert(&mut dependencies, "axios", json!("^1.4.0"));
+    }
+
+    if options.react_query {
+        json_insert(&mut dependencies, "@tanstack/react-query", json!("^4.35.3"));
+    }
+
+    match options.state.as_str() {
+        "zustand" => json_insert(&mut dependencies, "zustand", json!("^4.4.0")),
+        "redux" => {
+            json_insert(&mut dependencies, "@reduxjs/toolkit", json!("^1.9.5"));
+            json_insert(&mut dependencies, "react-redux", json!("^8.1.2"));
+        }
+        "jotai" => json_insert(&mut dependencies, "jotai", json!("^2.4.1")),
+        _ => {}
+    }
+
+    dependencies
 }
 
-fn get_dev_dependencies(
-    template: &String,
-    commit_lint: bool,
-    js_lint: &String,
-    style_lint: bool,
-) -> Value {
-    let rzpack_version = match tools::run_command("npm", &["view", "rzpack", "version"]) {
-        Ok(output) => String::from_utf8_lossy(&output.stdout)
-            .replacen("\n", "", 1)
-            .to_string(),
-        Err(_) => String::from("0.1.13"),
+/// 通过npm view获取包的最新版本，网络不可用时最多等待NPM_VIEW_TIMEOUT
+fn get_latest_version(package: &str) -> Option<String> {
+    let options = tools::CommandOptions {
+        timeout: Some(NPM_VIEW_TIMEOUT),
+        ..Default::default()
     };
+    match tools::run_command("npm", &["view", package, "version"], &options) {
+        Ok(output) => Some(output.stdout.trim().to_string()),
+        Err(e) => {
+            log::info(format!("获取{}最新版本失败，使用默认版本: {}", package, e));
+            None
+        }
+    }
+}
+
+fn get_dev_dependencies(options: &Prompts) -> Value {
+    let rzpack_version = get_latest_version("rzpack").unwrap_or(String::from("0.1.13"));
     let rzpack_version = format!("^{}", rzpack_version);
 
-    let is_admin_template = ["admin", "admin_header_menu"].contains(&template.as_str());
-    let js_lint_packages = get_js_lint_packages(js_lint.as_str());
+    let is_admin_template = options.template == "admin";
+    let workspace = options.workspace.as_ref();
+    // 工作区根目录已有格式化配置时沿用根目录的配置和依赖
+    let js_lint_packages = if workspace.is_some_and(|w| w.has_js_lint_config(&options.js_lint)) {
+        None
+    } else {
+        get_js_lint_packages(&options.js_lint)
+    };
+    let style_lint = options.style_lint && !workspace.is_some_and(|w| w.has_style_lint_config());
 
     let mut dev_dependencies = json!({
       "@types/react": "^18.0.25",
@@ -182,10 +327,20 @@ fn get_dev_dependencies(
       "rzpack":rzpack_version,
       "typescript": "5.1.6",
       "nodemon":"^3.0.1",
-      "simple-git-hooks": "^2.9.0",
-      "lint-staged": "^13.2.3",
     });
 
+    // 开发组件库和演示页面时需要安装peerDependencies，演示页面的antd中文配置依赖dayjs
+    if let Some(peer_dependencies) = get_peer_dependencies(options) {
+        dev_dependencies = json_merge(dev_dependencies, peer_dependencies);
+        json_insert(&mut dev_dependencies, "dayjs", json!("^1.11.9"));
+        json_insert(&mut dev_dependencies, "tsup", json!("^7.2.0"));
+    }
+
+    if workspace.is_none() {
+        json_insert(&mut dev_dependencies, "simple-git-hooks", json!("^2.9.0"));
+        json_insert(&mut dev_dependencies, "lint-staged", json!("^13.2.3"));
+    }
+
     dev_dependencies = match js_lint_packages {
         Some(value) => json_merge(dev_dependencies, value),
         None => dev_dependencies,
@@ -196,7 +351,7 @@ fn get_dev_dependencies(
         json_insert(&mut dev_dependencies, "@types/nprogress", json!("^0.2.0"));
     }
 
-    if commit_lint {
+    if options.commit_lint {
         json_insert(&mut dev_dependencies, "@commitlint/cli", json!("^17.6.7"));
         json_insert(
             &mut dev_dependencies,
@@ -214,29 +369,68 @@ fn get_dev_dependencies(
     }
 
     if style_lint {
-        dev_dependencies = json_merge(
-            dev_dependencies,
-            json!({
-              "stylelint": "^14.16.1".to_string(),
-              "stylelint-config-property-sort-order-smacss":"^9.1.0",
-              "stylelint-config-standard":"^29.0.0",
-              "stylelint-order": "^5.0.0",
-              "postcss-less":"^6.0.0",
-            }),
-        );
+        dev_dependencies = json_merge(dev_dependencies, get_style_lint_packages());
+    }
+
+    dev_dependencies = match get_test_packages(&options.test) {
+        Some(value) => json_merge(dev_dependencies, value),
+        None => dev_dependencies,
+    };
+
+    if options.e2e {
+        json_insert(&mut dev_dependencies, "@playwright/test", json!("^1.37.1"));
+        json_insert(&mut dev_dependencies, "@types/node", json!("^20.5.7"));
     }
 
-    return dev_dependencies;
+    if options.mock {
+        json_insert(&mut dev_dependencies, "concurrently", json!("^8.2.1"));
+    }
+
+    dev_dependencies
+}
+
+fn get_test_packages(test: &str) -> Option<Value> {
+    let testing_library_packages = json!({
+      "@testing-library/jest-dom": "^6.1.2",
+      "@testing-library/react": "^14.0.0",
+      "@testing-library/user-event": "^14.4.3",
+    });
+
+    let vitest_packages = json!({
+      "vitest": "^0.34.3",
+      "jsdom": "^22.1.0",
+      "@types/node": "^20.5.7",
+    });
+
+    let jest_packages = json!({
+      "jest": "^29.6.4",
+      "jest-environment-jsdom": "^29.6.4",
+      "@swc/core": "^1.3.80",
+      "@swc/jest": "^0.2.29",
+      "@types/jest": "^29.5.4",
+      "identity-obj-proxy": "^3.0.0",
+    });
+
+    match test {
+        "vitest" => Some(json_merge(testing_library_packages, vitest_packages)),
+        "jest" => Some(json_merge(testing_library_packages, jest_packages)),
+        _ => None,
+    }
+}
+
+pub fn get_style_lint_packages() -> Value {
+    json!({
+      "stylelint": "^14.16.1".to_string(),
+      "stylelint-config-property-sort-order-smacss":"^9.1.0",
+      "stylelint-config-standard":"^29.0.0",
+      "stylelint-order": "^5.0.0",
+      "postcss-less":"^6.0.0",
+    })
 }
 
-fn get_js_lint_packages(js_lint: &str) -> Option<Value> {
+pub fn get_js_lint_packages(js_lint: &str) -> Option<Value> {
     let rzpack_lint_version =
-        match tools::run_command("npm", &["view", "eslint-config-rzpack", "version"]) {
-            Ok(output) => String::from_utf8_lossy(&output.stdout)
-                .replacen("\n", "", 1)
-                .to_string(),
-            Err(_) => String::from("0.0.1"),
-        };
+        get_latest_version("eslint-config-rzpack").unwrap_or(String::from("0.0.1"));
 
     let rzpack_lint_version = format!("^{}", rzpack_lint_version);
 
@@ -0,0 +1,193 @@
+use crate::utils::prompts::Prompts;
+use std::error::Error;
+use std::fs;
+
+/// 在admin模板文件中插入的代码，(锚点, 替换锚点的内容)
+type Patch = (&'static str, &'static str);
+
+const COMPONENTS: &[Patch] = &[(
+    "export { default as AuthRoute } from './AuthRoute'\n",
+    concat!(
+        "export { default as Auth } from './Auth'\n",
+        "export { default as AuthRoute } from './AuthRoute'\n",
+    ),
+)];
+
+const MODEL: &[Patch] = &[
+    (
+        "  nickname: string\n",
+        concat!(
+            "  nickname: string\n",
+            "  // 拥有的权限(所有角色权限的合集)，*表示拥有所有权限\n",
+            "  permissions: string[]\n",
+        ),
+    ),
+    (
+        "  id: string\n",
+        concat!(
+            "  id: string\n",
+            "  // 角色拥有的权限\n",
+            "  permissions: string[]\n",
+        ),
+    ),
+];
+
+const ROUTER_TOOLS: &[Patch] = &[
+    (
+        "import { flattenDeepByKey } from '@/utils/tools'\n",
+        concat!(
+            "import { filterRoutesByPermission, getCachedPermissions } from '@/utils/permission'\n",
+            "import { flattenDeepByKey } from '@/utils/tools'\n",
+        ),
+    ),
+    (
+        "  path: string\n",
+        concat!(
+            "  path: string\n",
+            "  // 访问所需的权限，拥有其中任意一个即可访问，为空时不限制\n",
+            "  permissions?: string[]\n",
+        ),
+    ),
+    (
+        "  const userRoutes = flattenDeepByKey(renderRoutes(configs), 'children')\n",
+        concat!(
+            "  // 不注册没有权限的路由，即使服务端返回的菜单没有按权限过滤，也无法通过地址访问\n",
+            "  const permitted = filterRoutesByPermission(configs, getCachedPermissions())\n",
+            "  const userRoutes = flattenDeepByKey(renderRoutes(permitted), 'children')\n",
+        ),
+    ),
+];
+
+/// src/api/system.ts中的mock数据按登录的账号返回权限
+const MOCK_API: &[Patch] = &[
+    (
+        "import { routes } from '@/router'\n",
+        concat!(
+            "import type { UserInfoModel } from '@/model/system'\n",
+            "\n",
+            "import { routes } from '@/router'\n",
+            "import { ALL_PERMISSION, filterRoutesByPermission, getCachedPermissions } from '@/utils/permission'\n",
+            "import storage, { USER_INFO } from '@/utils/storage'\n",
+            "\n",
+            "// 使用guest账号登录可以查看按权限过滤菜单和路由的效果，其他账号拥有所有权限\n",
+            "const mockUsers: Record<string, Partial<UserInfoModel>> = {\n",
+            "  guest: {\n",
+            "    nickname: '访客',\n",
+            "    permissions: [],\n",
+            "    roleList: [{ id: '2', permissions: [], roleDesc: '只能访问不限制权限的页面', roleName: '访客' }],\n",
+            "    username: 'guest',\n",
+            "  },\n",
+            "  test001: {\n",
+            "    nickname: '测试001',\n",
+            "    permissions: [ALL_PERMISSION],\n",
+            "    roleList: [\n",
+            "      { id: '1', permissions: [ALL_PERMISSION], roleDesc: '拥有所有权限', roleName: '超级管理员' },\n",
+            "    ],\n",
+            "    username: 'test001',\n",
+            "  },\n",
+            "}\n",
+            "const getMockUser = (username = 'test001'): Partial<UserInfoModel> =>\n",
+            "  mockUsers[username] ?? { ...mockUsers.test001, username }\n",
+        ),
+    ),
+    (
+        concat!(
+            "          userInfo: {\n",
+            "            nickname: '测试001',\n",
+            "            username: values.username,\n",
+            "          },\n",
+        ),
+        "          userInfo: getMockUser(values.username),\n",
+    ),
+    (
+        "          menuTree: routes,\n",
+        concat!(
+            "          // 菜单权限一般由服务端过滤，这里根据用户拥有的权限过滤\n",
+            "          menuTree: filterRoutesByPermission(routes, getCachedPermissions()),\n",
+        ),
+    ),
+    (
+        concat!(
+            "        data: {\n",
+            "          nickname: '测试001',\n",
+            "          username: 'test001',\n",
+            "        },\n",
+        ),
+        "        data: getMockUser((storage.get(USER_INFO) as UserInfoModel | undefined)?.username),\n",
+    ),
+];
+
+/// 在admin模板的文件中插入权限控制的代码，新增的文件见template/rbac
+pub fn create(options: &Prompts) -> Result<(), Box<dyn Error>> {
+    let mut files = vec![
+        ("src/components/index.ts", COMPONENTS),
+        ("src/model/system.ts", MODEL),
+        ("src/router/tools.tsx", ROUTER_TOOLS),
+    ];
+    // 使用mock服务时src/api/system.ts中请求的是真实接口，没有mock数据
+    if !options.mock {
+        files.push(("src/api/system.ts", MOCK_API));
+    }
+
+    for (file, patches) in files {
+        let path = options.root.join(file);
+        let content = apply_patches(file, &fs::read_to_string(&path)?, patches)?;
+        fs::write(path, content)?;
+    }
+
+    Ok(())
+}
+
+/// 依次替换锚点，锚点在文件中不唯一时返回错误，避免模板修改后插入到错误的位置
+fn apply_patches(file: &str, content: &str, patches: &[Patch]) -> Result<String, String> {
+    let mut content = content.to_string();
+    for (anchor, replacement) in patches {
+        if content.matches(anchor).count() != 1 {
+            return Err(format!(
+                "{}中未找到唯一的插入位置`{}`",
+                file,
+                anchor.lines().next().unwrap_or_default().trim()
+            ));
+        }
+        content = content.replacen(anchor, replacement, 1);
+    }
+
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn patch_admin_template() {
+        let admin = Path::new(env!("CARGO_MANIFEST_DIR")).join("template/admin");
+        let files = [
+            ("src/components/index.ts", COMPONENTS),
+            ("src/model/system.ts", MODEL),
+            ("src/router/tools.tsx", ROUTER_TOOLS),
+            ("src/api/system.ts", MOCK_API),
+        ];
+        for (file, patches) in files {
+            let content = fs::read_to_string(admin.join(file)).unwrap();
+            let patched = apply_patches(file, &content, patches).unwrap();
+            for (_, replacement) in patches {
+                assert!(patched.contains(replacement));
+            }
+        }
+    }
+
+    #[test]
+    fn report_missing_anchor() {
+        assert_eq!(
+            apply_patches(
+                "src/model/system.ts",
+                "export interface RoleModel {}\n",
+                MODEL
+            )
+            .unwrap_err(),
+            "src/model/system.ts中未找到唯一的插入位置`nickname: string`"
+        );
+    }
+}
@@ -1,36 +1,281 @@
 use rust_embed::RustEmbed;
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
-use crate::utils::log;
+use crate::utils::{hooks::Hook, log};
 
 #[derive(RustEmbed)]
 #[folder = "template/"]
 struct Asset;
 
-pub fn copy(template: Vec<&str>, dest: &Path) -> Result<(), Box<dyn std::error::Error>> {
+/// 模板目录下的清单文件，不会被复制到项目中
+const MANIFEST: &str = "template.toml";
+
+#[derive(Deserialize, Default)]
+struct Manifest {
+    /// 继承的模板，只需要包含与继承模板不同的文件
+    extends: Option<String>,
+    /// 可选的变体，如`[variants.layout]`，每个选项对应按顺序覆盖的目录(相对于模板目录)
+    #[serde(default)]
+    variants: HashMap<String, HashMap<String, Vec<String>>>,
+    /// 项目创建完成后执行的命令，如`[[hooks]]`
+    #[serde(default)]
+    hooks: Vec<Hook>,
+}
+
+fn get_manifest(template: &str) -> Manifest {
+    match Asset::get(format!("{}/{}", template, MANIFEST).as_str()) {
+        Some(file) => {
+            let content = std::str::from_utf8(&file.data).expect("Failed to convert to UTF-8");
+            toml::from_str(content).expect("Failed to parse template manifest")
+        }
+        None => Manifest::default(),
+    }
+}
+
+/// 按继承关系展开模板，被继承的模板排在前面
+fn resolve_templates(template: Vec<&str>) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    expand_templates(template, |name| get_manifest(name).extends)
+}
+
+/// 按get_extends返回的继承关系展开模板，存在循环继承时返回错误
+fn expand_templates(
+    template: Vec<&str>,
+    get_extends: impl Fn(&str) -> Option<String>,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut templates: Vec<String> = vec![];
+
+    for name in template {
+        let mut chain = vec![name.to_string()];
+        while let Some(parent) = get_extends(chain[0].as_str()) {
+            if chain.contains(&parent) {
+                return Err(format!("模板{}存在循环继承", name).into());
+            }
+            chain.insert(0, parent);
+        }
+
+        for item in chain {
+            if !templates.contains(&item) {
+                templates.push(item);
+            }
+        }
+    }
+
+    Ok(templates)
+}
+
+/// 获取模板清单中声明的命令，按模板顺序排列
+pub fn get_hooks(template: Vec<&str>) -> Result<Vec<Hook>, Box<dyn std::error::Error>> {
+    Ok(resolve_templates(template)?
+        .iter()
+        .flat_map(|subdir| get_manifest(subdir.as_str()).hooks)
+        .collect())
+}
+
+/// 获取模板中选中变体对应的目录，以及所有变体目录(这些目录不参与模板本身的复制)
+fn resolve_variants(
+    template: &str,
+    manifest: &Manifest,
+    selected: &[(&str, &str)],
+) -> Result<(Vec<String>, Vec<String>), Box<dyn std::error::Error>> {
+    let mut dirs: Vec<String> = vec![];
+    let mut excluded: Vec<String> = vec![];
+
+    for (name, options) in &manifest.variants {
+        for dir in options.values().flatten() {
+            excluded.push(format!("{}/{}/", template, dir));
+        }
+
+        let value = selected
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| format!("模板{}缺少变体{}的值", template, name))?;
+        let option = options
+            .get(value)
+            .ok_or_else(|| format!("模板{}的变体{}不支持{}", template, name, value))?;
+        for dir in option {
+            if !dirs.contains(dir) {
+                dirs.push(dir.to_string());
+            }
+        }
+    }
+
+    Ok((dirs, excluded))
+}
+
+fn copy_dir(
+    subdir: &str,
+    excluded: &[String],
+    dest: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let prefix = format!("{}/", subdir);
+
     for file in Asset::iter() {
         let filename = file.trim();
-        // 获取文件名的前缀作为子目录名
-        let prefix = Path::new(filename).components().next().unwrap();
-        let subdir = prefix.as_os_str().to_str().unwrap_or("");
+        if excluded
+            .iter()
+            .any(|dir| filename.starts_with(dir.as_str()))
+        {
+            continue;
+        }
 
         // 如果文件位于需要的子目录下，将其写入目标文件夹
-        if template.contains(&subdir) {
-            let relative_path = filename.strip_prefix(subdir).unwrap().replacen("/", "", 1);
-            let file_path = dest.join(relative_path);
+        let relative_path = match filename.strip_prefix(prefix.as_str()) {
+            Some(path) if path != MANIFEST => path,
+            _ => continue,
+        };
+        let file_path = dest.join(relative_path);
 
-            // 创建目录（如果不存在）
-            if let Some(parent) = file_path.parent() {
-                fs::create_dir_all(parent)?;
-            }
+        // 创建目录（如果不存在）
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let content = Asset::get(filename).unwrap();
+        // 写入嵌入的文件内容
+        fs::write(&file_path, content.data)?;
+        log::info(format!("创建文件成功：{:?}", file_path));
+    }
 
-            let content = Asset::get(filename).unwrap();
-            // 写入嵌入的文件内容
-            fs::write(&file_path, content.data)?;
-            log::info(format!("创建文件成功：{:?}", file_path));
+    Ok(())
+}
+
+/// 复制模板，variants为选中的变体，如`[("layout", "side")]`
+pub fn copy(
+    template: Vec<&str>,
+    variants: &[(&str, &str)],
+    dest: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // 按顺序复制，后面模板中的同名文件会覆盖前面的
+    for subdir in resolve_templates(template)? {
+        let manifest = get_manifest(subdir.as_str());
+        let (dirs, excluded) = resolve_variants(subdir.as_str(), &manifest, variants)?;
+
+        copy_dir(subdir.as_str(), &excluded, dest)?;
+        // 选中的变体紧跟在模板本身之后复制，会被后续模板覆盖
+        for dir in dirs {
+            copy_dir(format!("{}/{}", subdir, dir).as_str(), &[], dest)?;
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    fn extends_of<'a>(pairs: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            pairs
+                .iter()
+                .find(|(child, _)| *child == name)
+                .map(|(_, parent)| parent.to_string())
+        }
+    }
+
+    #[test]
+    fn expand_extends_before_template() {
+        let pairs = [("lib", "antd"), ("antd", "react")];
+        assert_eq!(
+            expand_templates(vec!["base", "lib", "antd", "mock"], extends_of(&pairs)).unwrap(),
+            vec!["base", "react", "antd", "lib", "mock"]
+        );
+        assert_eq!(
+            resolve_templates(vec!["base", "lib"]).unwrap(),
+            vec!["base", "antd", "lib"]
+        );
+    }
+
+    #[test]
+    fn report_extends_cycle() {
+        let pairs = [("a", "b"), ("b", "c"), ("c", "a")];
+        assert_eq!(
+            expand_templates(vec!["base", "a"], extends_of(&pairs))
+                .unwrap_err()
+                .to_string(),
+            "模板a存在循环继承"
+        );
+        assert!(expand_templates(vec!["a"], extends_of(&[("a", "a")])).is_err());
+    }
+
+    #[test]
+    fn select_variant_dirs() {
+        let manifest: Manifest = toml::from_str(
+            "[variants.layout]\nside = [\"layouts/sider\"]\nmixed = [\"layouts/sider\", \"layouts/mixed\"]\n",
+        )
+        .unwrap();
+
+        let (dirs, mut excluded) =
+            resolve_variants("admin", &manifest, &[("layout", "mixed")]).unwrap();
+        assert_eq!(dirs, vec!["layouts/sider", "layouts/mixed"]);
+        excluded.sort();
+        assert_eq!(
+            excluded,
+            vec![
+                "admin/layouts/mixed/",
+                "admin/layouts/sider/",
+                "admin/layouts/sider/"
+            ]
+        );
+
+        assert_eq!(
+            resolve_variants("admin", &manifest, &[])
+                .unwrap_err()
+                .to_string(),
+            "模板admin缺少变体layout的值"
+        );
+        assert_eq!(
+            resolve_variants("admin", &manifest, &[("layout", "top")])
+                .unwrap_err()
+                .to_string(),
+            "模板admin的变体layout不支持top"
+        );
+        assert_eq!(
+            resolve_variants("antd", &Manifest::default(), &[("layout", "side")]).unwrap(),
+            (vec![], vec![])
+        );
+    }
+
+    #[test]
+    fn copy_with_overrides() {
+        let dest = env::temp_dir().join(format!("create-rzpack-template-{}", process::id()));
+        let _ = fs::remove_dir_all(&dest);
+
+        copy(vec!["lib"], &[], &dest).unwrap();
+        // lib中的文件覆盖被继承的antd中的同名文件，清单文件不会被复制
+        assert_eq!(
+            fs::read(dest.join("src/App.tsx")).unwrap(),
+            Asset::get("lib/src/App.tsx").unwrap().data.as_ref()
+        );
+        assert!(!dest.join(MANIFEST).exists());
+
+        copy(
+            vec!["admin"],
+            &[
+                ("layout", "side"),
+                ("state", "zustand"),
+                ("http", "fetch"),
+                ("router", "hash"),
+            ],
+            &dest,
+        )
+        .unwrap();
+        // 选中的变体覆盖模板中的同名文件，未选中的变体目录不会被复制
+        assert_eq!(
+            fs::read(dest.join("src/api/request.ts")).unwrap(),
+            Asset::get("admin/http/fetch/src/api/request.ts")
+                .unwrap()
+                .data
+                .as_ref()
+        );
+        assert!(!dest.join("http").exists());
+        assert!(!dest.join("layouts").exists());
+
+        fs::remove_dir_all(&dest).unwrap();
+    }
+}
@@ -1,15 +1,274 @@
-use std::io::{Error, Result};
-use std::process::{Command, Output};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
-pub fn run_command(command: &str, args: &[&str]) -> Result<Output> {
-    let output = Command::new(command).args(args).output()?;
+/// 错误信息中保留的输出行数
+const TAIL_LINES: usize = 10;
 
-    if output.status.success() {
-        Ok(output)
+/// 命令执行选项
+#[derive(Debug, Clone, Default)]
+pub struct CommandOptions<'a> {
+    /// 工作目录，默认为当前目录
+    pub cwd: Option<&'a Path>,
+    /// 额外设置的环境变量
+    pub envs: Vec<(&'a str, &'a str)>,
+    /// 超时时间，超时后结束进程
+    pub timeout: Option<Duration>,
+    /// 是否将输出逐行打印到终端
+    pub stream: bool,
+    /// 打印输出时每行的前缀
+    pub prefix: &'a str,
+}
+
+/// 命令执行成功后的输出
+#[derive(Debug)]
+pub struct CommandOutput {
+    pub stdout: String,
+}
+
+#[derive(Debug)]
+pub enum CommandError {
+    /// 命令无法启动，如命令不存在
+    Spawn { command: String, source: io::Error },
+    /// 等待进程结束时出错
+    Wait { command: String, source: io::Error },
+    /// 超时被结束
+    Timeout { command: String, timeout: Duration },
+    /// 退出码不为0，tail为stderr(为空时取stdout)的最后几行
+    Failed {
+        command: String,
+        status: ExitStatus,
+        tail: String,
+    },
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Spawn { command, source } => {
+                write!(f, "命令`{}`启动失败: {}", command, source)
+            }
+            CommandError::Wait { command, source } => {
+                write!(f, "等待命令`{}`结束失败: {}", command, source)
+            }
+            CommandError::Timeout { command, timeout } => {
+                write!(f, "命令`{}`超过{}s未完成", command, timeout.as_secs_f32())
+            }
+            CommandError::Failed {
+                command,
+                status,
+                tail,
+            } => {
+                write!(f, "命令`{}`执行失败({})", command, status)?;
+                if !tail.is_empty() {
+                    write!(f, "\n{}", tail)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for CommandError {}
+
+/// 执行命令并收集stdout/stderr，stream为true时同时逐行打印到终端
+pub fn run_command(
+    command: &str,
+    args: &[&str],
+    options: &CommandOptions,
+) -> Result<CommandOutput, CommandError> {
+    let display = std::iter::once(command)
+        .chain(args.iter().copied())
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    let mut cmd = Command::new(command);
+    cmd.args(args)
+        .envs(options.envs.iter().copied())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(cwd) = options.cwd {
+        cmd.current_dir(cwd);
+    }
+
+    let mut child = cmd.spawn().map_err(|source| CommandError::Spawn {
+        command: display.clone(),
+        source,
+    })?;
+    let stdout = read_lines(child.stdout.take(), options, false);
+    let stderr = read_lines(child.stderr.take(), options, true);
+
+    let status = match wait(&mut child, options.timeout) {
+        Ok(Some(status)) => status,
+        // 已结束的进程的子进程可能仍占用输出管道，不再等待读取线程
+        Ok(None) => {
+            return Err(CommandError::Timeout {
+                command: display,
+                timeout: options.timeout.unwrap_or_default(),
+            })
+        }
+        Err(source) => {
+            return Err(CommandError::Wait {
+                command: display,
+                source,
+            })
+        }
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    if status.success() {
+        Ok(CommandOutput { stdout })
     } else {
-        Err(Error::new(
-            std::io::ErrorKind::Other,
-            "Command execution failed",
-        ))
+        let output = if stderr.trim().is_empty() {
+            &stdout
+        } else {
+            &stderr
+        };
+        Err(CommandError::Failed {
+            command: display,
+            status,
+            tail: get_tail(output),
+        })
+    }
+}
+
+/// 在新线程中读取输出，避免管道写满阻塞子进程
+fn read_lines<R: Read + Send + 'static>(
+    reader: Option<R>,
+    options: &CommandOptions,
+    is_stderr: bool,
+) -> JoinHandle<String> {
+    let stream = options.stream;
+    let prefix = options.prefix.to_string();
+
+    thread::spawn(move || {
+        let mut content = String::new();
+        let Some(reader) = reader else {
+            return content;
+        };
+
+        for line in BufReader::new(reader).lines().map_while(Result::ok) {
+            if stream {
+                if is_stderr {
+                    eprintln!("{}{}", prefix, line);
+                } else {
+                    println!("{}{}", prefix, line);
+                }
+            }
+            content.push_str(&line);
+            content.push('\n');
+        }
+        content
+    })
+}
+
+/// 等待进程结束，超时后结束进程并返回None
+fn wait(child: &mut Child, timeout: Option<Duration>) -> io::Result<Option<ExitStatus>> {
+    let Some(timeout) = timeout else {
+        return child.wait().map(Some);
+    };
+
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if start.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+fn get_tail(output: &str) -> String {
+    let lines: Vec<&str> = output.trim_end().lines().collect();
+    lines[lines.len().saturating_sub(TAIL_LINES)..].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_stdout() {
+        let options = CommandOptions {
+            stream: true,
+            prefix: "  │ ",
+            ..Default::default()
+        };
+        let output = run_command("sh", &["-c", "echo hello; echo world >&2"], &options).unwrap();
+        // 打印时的前缀不会出现在收集的输出中
+        assert_eq!(output.stdout, "hello\n");
+    }
+
+    #[test]
+    fn run_with_cwd_and_envs() {
+        let dir = std::env::temp_dir();
+        let options = CommandOptions {
+            cwd: Some(dir.as_path()),
+            envs: vec![("RZPACK_TEST", "1")],
+            ..Default::default()
+        };
+        let output = run_command("sh", &["-c", "pwd; echo $RZPACK_TEST"], &options).unwrap();
+        let lines: Vec<&str> = output.stdout.lines().collect();
+        assert_eq!(
+            Path::new(lines[0]).canonicalize().unwrap(),
+            dir.canonicalize().unwrap()
+        );
+        assert_eq!(lines[1], "1");
+    }
+
+    #[test]
+    fn kill_after_timeout() {
+        let options = CommandOptions {
+            timeout: Some(Duration::from_millis(200)),
+            ..Default::default()
+        };
+        let start = Instant::now();
+        let error = run_command("sh", &["-c", "sleep 5"], &options).unwrap_err();
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(matches!(error, CommandError::Timeout { .. }));
+        assert_eq!(error.to_string(), "命令`sh -c sleep 5`超过0.2s未完成");
+    }
+
+    #[test]
+    fn keep_tail_of_failed_output() {
+        let script = "for i in $(seq 1 15); do echo line$i; done; exit 3";
+        let error = run_command("sh", &["-c", script], &CommandOptions::default()).unwrap_err();
+        let CommandError::Failed { status, tail, .. } = error else {
+            panic!("unexpected error: {}", error);
+        };
+        assert_eq!(status.code(), Some(3));
+        let expected: Vec<String> = (6..=15).map(|i| format!("line{}", i)).collect();
+        assert_eq!(tail, expected.join("\n"));
+    }
+
+    #[test]
+    fn prefer_stderr_in_tail() {
+        let script = "echo out; echo err >&2; exit 1";
+        let error = run_command("sh", &["-c", script], &CommandOptions::default()).unwrap_err();
+        assert!(matches!(error, CommandError::Failed { ref tail, .. } if tail == "err"));
+    }
+
+    #[test]
+    fn report_missing_binary() {
+        let error = run_command(
+            "create-rzpack-missing-binary",
+            &["--version"],
+            &CommandOptions::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(error, CommandError::Spawn { .. }));
+        assert!(error
+            .to_string()
+            .starts_with("命令`create-rzpack-missing-binary --version`启动失败"));
     }
 }
@@ -0,0 +1,374 @@
+use regex::Regex;
+use serde_json::Value;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 项目所在的pnpm/yarn/npm工作区
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    /// 工作区根目录
+    pub root: PathBuf,
+    /// 工作区使用的包管理工具
+    pub package_manager: String,
+    /// 工作区中配置的包路径，如：packages/*
+    pub packages: Vec<String>,
+}
+
+/// 从指定目录开始向上查找所在的工作区
+pub fn detect(start: &Path) -> Option<Workspace> {
+    start.ancestors().find_map(read_workspace)
+}
+
+fn read_workspace(dir: &Path) -> Option<Workspace> {
+    let pnpm_workspace = dir.join("pnpm-workspace.yaml");
+    if pnpm_workspace.is_file() {
+        let content = fs::read_to_string(pnpm_workspace).ok()?;
+        let config: serde_yaml::Value = serde_yaml::from_str(&content).ok()?;
+        let packages = config["packages"]
+            .as_sequence()
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        return Some(Workspace {
+            root: dir.to_path_buf(),
+            package_manager: String::from("pnpm"),
+            packages,
+        });
+    }
+
+    let content = fs::read_to_string(dir.join("package.json")).ok()?;
+    let pkg: Value = serde_json::from_str(&content).ok()?;
+    // workspaces可以是数组，也可以是{ packages: [] }
+    let workspaces = match &pkg["workspaces"] {
+        Value::Array(items) => items,
+        Value::Object(config) => config.get("packages")?.as_array()?,
+        _ => return None,
+    };
+    let packages = workspaces
+        .iter()
+        .filter_map(|item| item.as_str().map(String::from))
+        .collect();
+    let is_yarn = dir.join("yarn.lock").is_file()
+        || pkg["packageManager"]
+            .as_str()
+            .is_some_and(|value| value.starts_with("yarn"));
+
+    Some(Workspace {
+        root: dir.to_path_buf(),
+        package_manager: String::from(if is_yarn { "yarn" } else { "npm" }),
+        packages,
+    })
+}
+
+impl Workspace {
+    /// 新项目所在的目录，取工作区中的第一个包目录(优先apps/*)，没有时使用packages目录
+    pub fn packages_dir(&self) -> PathBuf {
+        let dirs: Vec<&str> = self
+            .packages
+            .iter()
+            .filter_map(|pattern| {
+                let pattern = pattern.trim_start_matches("./");
+                let dir = pattern
+                    .strip_suffix("/**")
+                    .or_else(|| pattern.strip_suffix("/*"))?;
+                if dir.contains('*') || dir.starts_with('!') {
+                    None
+                } else {
+                    Some(dir)
+                }
+            })
+            .collect();
+        let dir = dirs
+            .iter()
+            .find(|dir| **dir == "apps")
+            .or(dirs.first())
+            .copied()
+            .unwrap_or("packages");
+
+        self.root.join(dir)
+    }
+
+    /// 将项目注册到工作区配置中，已被工作区中的包路径匹配时不做处理，返回是否有修改
+    pub fn register(&self, project_dir: &Path) -> Result<bool, Box<dyn Error>> {
+        let relative = project_dir
+            .strip_prefix(&self.root)?
+            .to_string_lossy()
+            .replace('\\', "/");
+        if self
+            .packages
+            .iter()
+            .any(|pattern| matches(pattern, &relative))
+        {
+            return Ok(false);
+        }
+
+        if self.package_manager == "pnpm" {
+            let path = self.root.join("pnpm-workspace.yaml");
+            let content = fs::read_to_string(&path)?;
+            fs::write(path, insert_pnpm_package(&content, &relative)?)?;
+        } else {
+            // 直接在原文本中插入，避免重新序列化打乱package.json的字段顺序
+            let path = self.root.join("package.json");
+            let content = fs::read_to_string(&path)?;
+            let re = Regex::new(r#""workspaces"\s*:\s*(\{[^}]*?"packages"\s*:\s*)?\[\s*"#)?;
+            let found = re.find(&content).ok_or("未找到workspaces配置")?;
+            let end = found.end();
+            // 沿用数组中原有的换行缩进
+            let separator = if content[end..].starts_with(']') {
+                String::new()
+            } else {
+                let indent = found.as_str().rsplit('[').next().unwrap_or_default();
+                String::from(",") + if indent.is_empty() { " " } else { indent }
+            };
+            let content = String::from(&content[..end])
+                + format!("\"{}\"{}", relative, separator).as_str()
+                + &content[end..];
+            fs::write(path, content)?;
+        }
+
+        Ok(true)
+    }
+
+    /// 工作区根目录是否已有对应的js格式化工具配置
+    pub fn has_js_lint_config(&self, js_lint: &str) -> bool {
+        match js_lint {
+            "" => false,
+            "rome" => self.has_config(&["rome.json"], None),
+            _ => self.has_config(
+                &[
+                    ".eslintrc",
+                    ".eslintrc.js",
+                    ".eslintrc.cjs",
+                    ".eslintrc.json",
+                    ".eslintrc.yml",
+                    ".eslintrc.yaml",
+                    "eslint.config.js",
+                    "eslint.config.mjs",
+                    "eslint.config.cjs",
+                ],
+                Some("eslintConfig"),
+            ),
+        }
+    }
+
+    /// 工作区根目录是否已有stylelint配置
+    pub fn has_style_lint_config(&self) -> bool {
+        self.has_config(
+            &[
+                ".stylelintrc",
+                ".stylelintrc.js",
+                ".stylelintrc.cjs",
+                ".stylelintrc.json",
+                ".stylelintrc.yml",
+                ".stylelintrc.yaml",
+                "stylelint.config.js",
+                "stylelint.config.cjs",
+            ],
+            Some("stylelint"),
+        )
+    }
+
+    fn has_config(&self, files: &[&str], package_field: Option<&str>) -> bool {
+        if files.iter().any(|file| self.root.join(file).is_file()) {
+            return true;
+        }
+
+        package_field.is_some_and(|field| {
+            fs::read_to_string(self.root.join("package.json"))
+                .ok()
+                .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+                .is_some_and(|pkg| pkg.get(field).is_some())
+        })
+    }
+}
+
+/// 简单的包路径匹配，支持packages/*、packages/**以及完整路径
+fn matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+    if let Some(dir) = pattern.strip_suffix("/**") {
+        path.starts_with(&format!("{}/", dir))
+    } else if let Some(dir) = pattern.strip_suffix("/*") {
+        path.strip_prefix(&format!("{}/", dir))
+            .is_some_and(|name| !name.is_empty() && !name.contains('/'))
+    } else {
+        pattern == path
+    }
+}
+
+/// 在pnpm-workspace.yaml的packages中插入路径，直接修改原文本以保留注释和格式，沿用已有条目的缩进和引号
+fn insert_pnpm_package(content: &str, relative: &str) -> Result<String, Box<dyn Error>> {
+    // 块格式的列表，插入到第一个条目之前
+    let block = Regex::new(
+        r#"(?m)^packages:[ \t]*(?:#.*)?\n(?:[ \t]*(?:#.*)?\n)*?([ \t]*)-[ \t]*(['"]?)"#,
+    )?;
+    if let Some(captures) = block.captures(content) {
+        let start = captures.get(1).unwrap().start();
+        let quote = &captures[2];
+        return Ok(String::from(&content[..start])
+            + format!("{}- {}{}{}\n", &captures[1], quote, relative, quote).as_str()
+            + &content[start..]);
+    }
+
+    // 行内格式的列表，插入到第一个元素之前
+    let flow = Regex::new(r"(?m)^packages:[ \t]*\[[ \t]*")?;
+    if let Some(found) = flow.find(content) {
+        let end = found.end();
+        let rest = &content[end..];
+        let quote = if rest.starts_with('"') { "\"" } else { "'" };
+        let separator = if rest.starts_with(']') { "" } else { ", " };
+        return Ok(String::from(&content[..end])
+            + format!("{}{}{}{}", quote, relative, quote, separator).as_str()
+            + rest);
+    }
+
+    let item = format!("  - '{}'", relative);
+    // packages为空时在其后插入条目，没有packages时追加到文件末尾
+    let empty = Regex::new(r"(?m)^packages:[ \t]*(?:#.*)?$")?;
+    if let Some(found) = empty.find(content) {
+        let end = found.end();
+        return Ok(String::from(&content[..end]) + "\n" + item.as_str() + &content[end..]);
+    }
+
+    let separator = if content.is_empty() || content.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+    Ok(String::from(content) + separator + "packages:\n" + item.as_str() + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    fn create_workspace(name: &str, file: &str, content: &str) -> Workspace {
+        let dir = env::temp_dir().join(format!("create-rzpack-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(file), content).unwrap();
+        read_workspace(&dir).unwrap()
+    }
+
+    #[test]
+    fn match_package_patterns() {
+        assert!(matches("apps/*", "apps/admin"));
+        assert!(matches("./apps/*", "apps/admin"));
+        assert!(!matches("apps/*", "apps/admin/web"));
+        assert!(!matches("apps/*", "apps"));
+        assert!(matches("apps/**", "apps/admin/web"));
+        assert!(!matches("apps/**", "packages/ui"));
+        assert!(matches("tools/cli/", "tools/cli"));
+        assert!(!matches("tools/cli", "tools/cli/web"));
+    }
+
+    #[test]
+    fn detect_from_nested_dir() {
+        let workspace = create_workspace(
+            "detect",
+            "pnpm-workspace.yaml",
+            "packages:\n  - 'packages/*'\n",
+        );
+        let nested = workspace.root.join("packages/ui/src");
+        fs::create_dir_all(&nested).unwrap();
+
+        let detected = detect(&nested).unwrap();
+        assert_eq!(detected.root, workspace.root);
+        assert_eq!(detected.package_manager, "pnpm");
+        assert_eq!(detected.packages_dir(), workspace.root.join("packages"));
+
+        fs::remove_dir_all(&workspace.root).unwrap();
+    }
+
+    #[test]
+    fn register_pnpm_workspace() {
+        let workspace =
+            create_workspace("pnpm", "pnpm-workspace.yaml", "packages:\n  - 'apps/*'\n");
+        let root = &workspace.root;
+
+        assert!(!workspace.register(&root.join("apps/admin")).unwrap());
+        assert!(workspace.register(&root.join("tools/cli")).unwrap());
+        assert!(workspace.register(&env::temp_dir().join("other")).is_err());
+
+        assert_eq!(
+            fs::read_to_string(root.join("pnpm-workspace.yaml")).unwrap(),
+            "packages:\n  - 'tools/cli'\n  - 'apps/*'\n"
+        );
+        let workspace = read_workspace(root).unwrap();
+        assert_eq!(workspace.packages, vec!["tools/cli", "apps/*"]);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn insert_pnpm_package_keeps_format() {
+        assert_eq!(
+            insert_pnpm_package(
+                "# 工作区配置\npackages: # 所有包\n\n    # 应用\n    - \"apps/*\"\n",
+                "tools/cli"
+            )
+            .unwrap(),
+            "# 工作区配置\npackages: # 所有包\n\n    # 应用\n    - \"tools/cli\"\n    - \"apps/*\"\n"
+        );
+        assert_eq!(
+            insert_pnpm_package("packages: [\"apps/*\"]\n", "tools/cli").unwrap(),
+            "packages: [\"tools/cli\", \"apps/*\"]\n"
+        );
+        assert_eq!(
+            insert_pnpm_package("packages: []\n", "tools/cli").unwrap(),
+            "packages: ['tools/cli']\n"
+        );
+        assert_eq!(
+            insert_pnpm_package("packages:\n", "tools/cli").unwrap(),
+            "packages:\n  - 'tools/cli'\n"
+        );
+        assert_eq!(
+            insert_pnpm_package("# 工作区配置", "tools/cli").unwrap(),
+            "# 工作区配置\npackages:\n  - 'tools/cli'\n"
+        );
+    }
+
+    #[test]
+    fn register_npm_workspace() {
+        let workspace = create_workspace(
+            "npm",
+            "package.json",
+            "{\n  \"name\": \"root\",\n  \"workspaces\": [\n    \"packages/*\"\n  ]\n}\n",
+        );
+        let root = &workspace.root;
+
+        assert!(!workspace.register(&root.join("packages/ui")).unwrap());
+        assert!(workspace.register(&root.join("apps/admin")).unwrap());
+        assert_eq!(
+            fs::read_to_string(root.join("package.json")).unwrap(),
+            "{\n  \"name\": \"root\",\n  \"workspaces\": [\n    \"apps/admin\",\n    \"packages/*\"\n  ]\n}\n"
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn register_yarn_workspace_packages() {
+        let workspace = create_workspace(
+            "yarn",
+            "package.json",
+            r#"{"packageManager":"yarn@3.6.1","workspaces":{"packages":[]}}"#,
+        );
+        let root = &workspace.root;
+        assert_eq!(workspace.package_manager, "yarn");
+
+        assert!(workspace.register(&root.join("apps/admin")).unwrap());
+        assert_eq!(
+            fs::read_to_string(root.join("package.json")).unwrap(),
+            r#"{"packageManager":"yarn@3.6.1","workspaces":{"packages":["apps/admin"]}}"#
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
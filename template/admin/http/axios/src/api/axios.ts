@@ -4,6 +4,7 @@ import axios from 'axios'
 import NProgress from 'nprogress'
 
 import { message } from '@/App'
+import { redirectToLogin } from '@/router'
 import storage, { TOKEN, USER_INFO } from '@/utils/storage'
 import { isUndef, recordValueTrim } from '@/utils/tools'
 
@@ -11,13 +12,13 @@ import 'nprogress/nprogress.css'
 NProgress.configure({ showSpinner: false })
 
 const instance: AxiosInstance = axios.create({
-  baseURL: '/api/omc',
+  baseURL: process.env.RZPACK_API_BASE_URL,
 }) as AxiosInstance
 
 const noAuth = () => {
   storage.remove(TOKEN)
   storage.remove(USER_INFO)
-  window.location.hash = '#/login'
+  redirectToLogin()
 }
 
 instance.interceptors.request.use(
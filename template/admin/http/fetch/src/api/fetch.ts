@@ -0,0 +1,155 @@
+import NProgress from 'nprogress'
+
+import { message } from '@/App'
+import { redirectToLogin } from '@/router'
+import storage, { TOKEN, USER_INFO } from '@/utils/storage'
+import { isUndef, recordValueTrim } from '@/utils/tools'
+
+import 'nprogress/nprogress.css'
+NProgress.configure({ showSpinner: false })
+
+const baseURL = process.env.RZPACK_API_BASE_URL ?? ''
+
+export interface RequestConfig extends Omit<RequestInit, 'body'> {
+  // 请求体，对象会被序列化为JSON
+  data?: unknown
+  // 查询参数
+  params?: Record<string, any>
+  // 响应类型，下载文件时使用blob
+  responseType?: 'blob' | 'json'
+  // 是否显示错误信息
+  showErrorMessage?: boolean
+}
+
+const noAuth = () => {
+  storage.remove(TOKEN)
+  storage.remove(USER_INFO)
+  redirectToLogin()
+}
+
+const getUrl = (url: string, params?: Record<string, any>) => {
+  const target = /^https?:\/\//.test(url) ? url : `${baseURL}${url}`
+  if (!params) {
+    return target
+  }
+
+  // 处理get请求参数值字符串左右空格，并过滤掉未定义的参数
+  const query = new URLSearchParams()
+  Object.entries(recordValueTrim(params)).forEach(([key, value]) => {
+    if (!isUndef(value)) {
+      query.append(key, String(value))
+    }
+  })
+  const search = query.toString()
+
+  return search ? `${target}${target.includes('?') ? '&' : '?'}${search}` : target
+}
+
+const getBody = (data: unknown, headers: Headers): BodyInit | undefined => {
+  if (isUndef(data)) {
+    return undefined
+  }
+
+  if (data instanceof FormData || data instanceof Blob || typeof data === 'string') {
+    return data
+  }
+
+  // 处理post请求参数值字符串左右空格
+  headers.set('Content-Type', 'application/json')
+  const record = data as Record<string, any>
+  return JSON.stringify(record.constructor === Object ? recordValueTrim(record) : record)
+}
+
+const request = async <T = unknown>(url: string, config: RequestConfig = {}): Response<T> => {
+  const { data, params, responseType = 'json', showErrorMessage = true, ...init } = config
+  const headers = new Headers(init.headers)
+  const token = storage.get(TOKEN)
+  // 如果在请求配置中指定了token，则不再添加token
+  if (!isUndef(token) && !headers.has('Authorization')) {
+    headers.set('Authorization', token as string)
+  }
+
+  NProgress.start()
+  let response: globalThis.Response
+  try {
+    response = await fetch(getUrl(url, params), {
+      ...init,
+      body: getBody(data, headers),
+      headers,
+    })
+  } finally {
+    NProgress.done()
+  }
+
+  if (response.status === 401) {
+    noAuth()
+    return Promise.reject(response)
+  }
+
+  if (!response.ok) {
+    if (response.status === 404) {
+      message.error(`未找到接口：${url}`)
+    }
+
+    if (response.status === 500) {
+      message.error(`接口：${url}在服务端发生未知错误`)
+    }
+
+    return Promise.reject(response)
+  }
+
+  // 下载文件
+  if (responseType === 'blob') {
+    return { code: 0, data: (await response.blob()) as T, msg: '' }
+  }
+
+  const body: ResponseBody<T> = await response.json()
+  if (body?.code === 401) {
+    noAuth()
+    return Promise.reject(body)
+  }
+
+  if (body?.code !== 0) {
+    if (showErrorMessage) {
+      message.error(body.msg)
+    }
+
+    return Promise.reject(body.msg)
+  }
+
+  return body
+}
+
+const instance = {
+  delete: <T = unknown>(url: string, config?: RequestConfig) =>
+    request<T>(url, { ...config, method: 'DELETE' }),
+  get: <T = unknown>(url: string, config?: RequestConfig) =>
+    request<T>(url, { ...config, method: 'GET' }),
+  patch: <T = unknown>(url: string, data?: unknown, config?: RequestConfig) =>
+    request<T>(url, { ...config, data, method: 'PATCH' }),
+  post: <T = unknown>(url: string, data?: unknown, config?: RequestConfig) =>
+    request<T>(url, { ...config, data, method: 'POST' }),
+  put: <T = unknown>(url: string, data?: unknown, config?: RequestConfig) =>
+    request<T>(url, { ...config, data, method: 'PUT' }),
+  request,
+}
+
+export default instance
+
+export type Response<T = unknown> = Promise<ResponseBody<T>>
+export interface ResponseBody<T> {
+  code: number
+  data: T
+  msg: string
+}
+export interface ResponseDataList<T> {
+  pageNo?: number
+  pageSize?: number
+  records: T[]
+  totalRecord: number
+}
+// 分页参数
+export interface PaginationParams {
+  pageNo?: number
+  pageSize?: number
+}
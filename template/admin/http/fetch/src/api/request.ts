@@ -0,0 +1,3 @@
+// 请求层的统一出口，接口文件都从这里引入请求实例和类型
+export { default } from './fetch'
+export * from './fetch'
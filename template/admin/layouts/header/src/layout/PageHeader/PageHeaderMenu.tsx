@@ -0,0 +1,57 @@
+import type { RouteModel } from '@/router'
+import type { ItemType } from 'antd/lib/menu/hooks/useItems'
+
+import { Menu } from 'antd'
+import React from 'react'
+import { useLocation, useNavigate } from 'react-router-dom'
+
+import { DynamicIcon } from '@/components'
+import useRouterStore from '@/stores/router'
+import { flattenDeepByKey } from '@/utils/tools'
+
+import classes from './index.module.less'
+
+const HeaderMenu = () => {
+  const location = useLocation()
+  const navigate = useNavigate()
+  const auths = useRouterStore(state => state.userAuths)
+  const menus = useRouterStore(state => state.menus)
+
+  let selectedKeys: string[] = [location.pathname]
+  // 获取当前路由对象
+  const currentRoute = flattenDeepByKey(auths, 'children')?.find(
+    item => item.path === location.pathname
+  )
+  // 如果当前路由的菜单是隐藏的，则通过meta配置activeMenuPath的值来设置高亮路由
+  if (currentRoute?.hidden && currentRoute?.meta) {
+    const meta = JSON.parse(currentRoute.meta)
+    if (meta?.activeMenuPath) {
+      selectedKeys = [meta.activeMenuPath]
+    }
+  }
+
+  const onClick = ({ key }: { key: string }) => navigate(key)
+
+  return (
+    <Menu
+      className={classes.menu}
+      items={getMenuItems(menus)}
+      mode="horizontal"
+      onClick={onClick}
+      selectedKeys={selectedKeys}
+    />
+  )
+}
+
+export default HeaderMenu
+
+const getMenuItems = (routes?: RouteModel[]): ItemType[] => {
+  return (
+    routes?.map(item => ({
+      children: item?.children?.length ? getMenuItems(item.children) : undefined,
+      icon: item.icon ? <DynamicIcon name={`menu/${item.icon}`} /> : null,
+      key: item.path,
+      label: item.title,
+    })) ?? []
+  )
+}
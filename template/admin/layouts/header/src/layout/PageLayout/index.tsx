@@ -1,24 +1,19 @@
 import { Layout } from 'antd'
 import React from 'react'
-import { useState } from 'react'
 
 import PageContent from '../PageContent'
 import PageHeader from '../PageHeader'
-import PageSlider from '../PageSide'
 import { AuthRoute, AutoFirstPath } from '@/components'
 
 import classes from './index.module.less'
 
 const PageLayout = () => {
-  const [collapsed, setCollapsed] = useState(false)
-
   return (
     <AuthRoute>
       <AutoFirstPath>
         <Layout>
-          <PageHeader collapsed={collapsed} />
+          <PageHeader />
           <Layout className={classes.layout}>
-            <PageSlider collapsed={collapsed} onCollapsedChange={setCollapsed} />
             <PageContent />
           </Layout>
         </Layout>
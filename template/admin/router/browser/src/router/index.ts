@@ -0,0 +1,20 @@
+import { createBrowserRouter } from 'react-router-dom'
+
+export { default as routes } from './routes'
+export * from './tools'
+
+// 使用history模式的路由，部署时需要服务端将所有路由地址指向index.html
+export const createRouter = createBrowserRouter
+
+/**
+ * 获取当前路由地址，可以在组件之外使用
+ * @returns 当前路由地址，如/login
+ */
+export const getCurrentPath = () => window.location.pathname
+
+/**
+ * 跳转到登录页，用于在组件之外(如请求拦截中)跳转
+ */
+export const redirectToLogin = () => {
+  window.location.href = '/login'
+}
@@ -0,0 +1,20 @@
+import { createHashRouter } from 'react-router-dom'
+
+export { default as routes } from './routes'
+export * from './tools'
+
+// 使用hash模式的路由，部署时不需要服务端配置
+export const createRouter = createHashRouter
+
+/**
+ * 获取当前路由地址，可以在组件之外使用
+ * @returns 当前路由地址，如/login
+ */
+export const getCurrentPath = () => window.location.hash.replace(/^#/, '') || '/'
+
+/**
+ * 跳转到登录页，用于在组件之外(如请求拦截中)跳转
+ */
+export const redirectToLogin = () => {
+  window.location.hash = '#/login'
+}
@@ -10,10 +10,10 @@ import {
 } from 'antd'
 import React from 'react'
 import { useEffect, useRef } from 'react'
-import { createHashRouter, RouterProvider } from 'react-router-dom'
+import { RouterProvider } from 'react-router-dom'
 
 import { CenterSpin } from './components'
-import { mergeUserRoutes } from './router'
+import { createRouter, getCurrentPath, mergeUserRoutes } from './router'
 import useRouterStore from './stores/router'
 import storage, { TOKEN } from './utils/storage'
 import userInfoStore from '@/stores/user'
@@ -28,7 +28,7 @@ const App = () => {
   notification = staticFunctions.notification
   modal = staticFunctions.modal
 
-  const isLogin = window?.location?.hash === '#/login'
+  const isLogin = getCurrentPath() === '/login'
   const refreshUserInfo = userInfoStore(state => state.refreshUserInfo)
   const { getUserAuths, loading, userRoutes } = useRouterStore(state => ({
     getUserAuths: state.getUserAuths,
@@ -48,7 +48,7 @@ const App = () => {
     }
   }, [isLogin])
 
-  const router = createHashRouter(mergeUserRoutes(userRoutes))
+  const router = createRouter(mergeUserRoutes(userRoutes))
 
   return !loading ? <RouterProvider router={router} /> : <CenterSpin />
 }
@@ -1,7 +1,7 @@
 // import type { UserInfoModel } from '@/model/system'
 // import type { RouteModel } from '@/router'
-// import type { Response } from './axios'
-// import axios from './axios'
+// import type { Response } from './request'
+// import request from './request'
 
 // export interface LoginParams {
 //   // 账号
@@ -19,22 +19,22 @@
 //  * @returns 返回token和用户信息
 //  */
 // export const login = (params: LoginParams): Response<LoginResult> =>
-//   axios.post('/v1/system/login', params)
+//   request.post('/v1/system/login', params)
 // /**
 //  * 获取当前登录用户权限
 //  * @returns 返回菜单及按钮权限
 //  */
 // export const fetchAuths = (): Response<{ menuTree: RouteModel[]; buttonKeys: string[] }> =>
-//   axios.post('/v1/system/listMenuButtonByUser')
+//   request.post('/v1/system/listMenuButtonByUser')
 // /**
 //  * 退出登录
 //  */
-// export const logout = (): Response<unknown> => axios.post('/v1/system/logout')
+// export const logout = (): Response<unknown> => request.post('/v1/system/logout')
 // /**
 //  * 获取当前登录用户信息
 //  * @returns 返回用户信息
 //  */
-// export const fetchUserInfo = (): Response<UserInfoModel> => axios.post('/v1/system/userinfo')
+// export const fetchUserInfo = (): Response<UserInfoModel> => request.post('/v1/system/userinfo')
 
 // 实际开发时，打开上面的代码并改一下接口地址，并将下面mock数据的代码删除即可
 
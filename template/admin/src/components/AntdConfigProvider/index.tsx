@@ -1,8 +1,15 @@
-import { App, ConfigProvider } from 'antd'
+import { App, ConfigProvider, theme } from 'antd'
 import zhCN from 'antd/es/locale/zh_CN'
 import React from 'react'
 
-import token from '@/theme'
+import token, { algorithms } from '@/theme'
+
+// 将主题配置中的算法名称转换为antd的算法
+const algorithmMap = {
+  compact: theme.compactAlgorithm,
+  dark: theme.darkAlgorithm,
+  default: theme.defaultAlgorithm,
+}
 
 export interface AntdConfigProvider {
   children: React.ReactNode
@@ -15,7 +22,7 @@ const AntdConfigProvider = (props: AntdConfigProvider) => {
       autoInsertSpaceInButton={false}
       // 中文配置
       locale={zhCN}
-      theme={{ token }}
+      theme={{ algorithm: algorithms.map(item => algorithmMap[item]), token }}
     >
       <App>{props.children}</App>
     </ConfigProvider>
@@ -1,2 +0,0 @@
-export { default as routes } from './routes'
-export * from './tools'
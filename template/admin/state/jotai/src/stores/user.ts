@@ -0,0 +1,52 @@
+import type { UserInfoModel } from '@/model/system'
+
+import { atom, getDefaultStore, useAtomValue } from 'jotai'
+
+import { fetchUserInfo } from '@/api/system'
+import { TOKEN, USER_INFO } from '@/utils/storage'
+import storage from '@/utils/storage'
+
+export interface UserInfoStore {
+  clear: () => void
+  refreshUserInfo: () => void
+  setUserInfo: (token: string, data: UserInfoModel) => void
+  token?: string
+  userInfo?: UserInfoModel
+}
+
+type UserInfoState = Pick<UserInfoStore, 'token' | 'userInfo'>
+
+const userInfoAtom = atom<UserInfoState>({
+  token: storage.get(TOKEN) as string,
+  userInfo: undefined,
+})
+
+const set = (partial: Partial<UserInfoState>) =>
+  getDefaultStore().set(userInfoAtom, state => ({ ...state, ...partial }))
+
+const actions = {
+  clear: () => {
+    storage.remove(USER_INFO)
+    storage.remove(TOKEN)
+    set({ token: undefined, userInfo: undefined })
+  },
+  refreshUserInfo: async () => {
+    const { data } = await fetchUserInfo()
+    storage.set(USER_INFO, data)
+    set({ userInfo: data })
+  },
+  setUserInfo: (token: string, data: UserInfoModel) => {
+    storage.set(TOKEN, token)
+    storage.set(USER_INFO, data)
+    set({ token, userInfo: data })
+  },
+}
+
+/**
+ * 获取用户信息状态，用法与zustand创建的hook一致
+ * @param selector 从状态中选取需要的值
+ */
+const userInfoStore = <T>(selector: (state: UserInfoStore) => T): T =>
+  selector({ ...useAtomValue(userInfoAtom), ...actions })
+
+export default userInfoStore
@@ -0,0 +1,36 @@
+import { useSyncExternalStore } from 'react'
+
+type SetState<T> = (partial: Partial<T>) => void
+type GetState<T> = () => T
+
+/**
+ * 基于useSyncExternalStore的简易状态管理，用法与zustand的create一致
+ * @param initializer 返回初始状态及修改状态的方法
+ * @returns 返回接收selector的hook
+ */
+const createStore = <T extends object>(initializer: (set: SetState<T>, get: GetState<T>) => T) => {
+  const listeners = new Set<() => void>()
+  let state = {} as T
+
+  const get: GetState<T> = () => state
+  const set: SetState<T> = partial => {
+    state = { ...state, ...partial }
+    listeners.forEach(listener => listener())
+  }
+  const subscribe = (listener: () => void) => {
+    listeners.add(listener)
+    return () => listeners.delete(listener)
+  }
+
+  state = initializer(set, get)
+
+  // selector在订阅之外执行，允许返回新的对象或数组
+  const useStore = <S>(selector: (state: T) => S): S =>
+    selector(useSyncExternalStore(subscribe, get))
+  useStore.getState = get
+  useStore.setState = set
+
+  return useStore
+}
+
+export default createStore
@@ -0,0 +1,19 @@
+import { configureStore } from '@reduxjs/toolkit'
+
+import { routerSlice } from './router'
+import { userSlice } from './user'
+
+const store = configureStore({
+  // 路由状态中包含React元素，关闭序列化检查
+  middleware: getDefaultMiddleware => getDefaultMiddleware({ serializableCheck: false }),
+  reducer: {
+    router: routerSlice.reducer,
+    user: userSlice.reducer,
+  },
+})
+
+export type RootState = ReturnType<typeof store.getState>
+export type AppDispatch = typeof store.dispatch
+
+export default store
+export * from './router'
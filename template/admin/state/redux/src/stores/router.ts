@@ -1,7 +1,10 @@
+import type { RootState } from '.'
+import type { PayloadAction } from '@reduxjs/toolkit'
+
+import { createSlice } from '@reduxjs/toolkit'
 import { cloneDeep } from 'lodash-es'
+import { useDispatch, useSelector } from 'react-redux'
 import { type RouteObject, useLocation, useParams } from 'react-router-dom'
-import { create } from 'zustand'
-import { devtools } from 'zustand/middleware'
 
 import { fetchAuths } from '@/api/system'
 import { getUserRoutes, RouteModel } from '@/router'
@@ -19,6 +22,8 @@ export interface RouterStore {
   userRoutes: RouteObject[]
 }
 
+type RouterState = Omit<RouterStore, 'clear' | 'getUserAuths' | 'setLoading'>
+
 /**
  * 递归过滤掉除路由之外的数据
  * Tips: 此操作会改变数据源的children
@@ -33,13 +38,40 @@ export const deepFilterHidden = (item: RouteModel) => {
   return !item.hidden
 }
 
-const useRouterStore = create<RouterStore>()(
-  devtools(set => ({
-    buttonKeys: {},
+const initialState: RouterState = {
+  buttonKeys: {},
+  firstPath: '',
+  loading: false,
+  menus: [],
+  userAuths: [],
+  userRoutes: [],
+}
+
+export const routerSlice = createSlice({
+  initialState,
+  name: 'router',
+  reducers: {
+    setState: (state, action: PayloadAction<Partial<RouterState>>) => ({
+      ...state,
+      ...action.payload,
+    }),
+  },
+})
+
+/**
+ * 获取路由状态，用法与zustand创建的hook一致
+ * @param selector 从状态中选取需要的值
+ */
+const useRouterStore = <T>(selector: (state: RouterStore) => T): T => {
+  const dispatch = useDispatch()
+  const state = useSelector((state: RootState) => state.router)
+  const set = (partial: Partial<RouterState>) => dispatch(routerSlice.actions.setState(partial))
+
+  return selector({
+    ...state,
     clear: () => {
       set({ firstPath: '', menus: [], userAuths: [], userRoutes: [] })
     },
-    firstPath: '',
     getUserAuths: async () => {
       try {
         set({ loading: true })
@@ -53,13 +85,9 @@ const useRouterStore = create<RouterStore>()(
         set({ loading: false })
       }
     },
-    loading: false,
-    menus: [],
     setLoading: (status: boolean) => set({ loading: status }),
-    userAuths: [],
-    userRoutes: [],
-  }))
-)
+  })
+}
 
 /**
  * 获取当前页面按钮权限
@@ -0,0 +1,67 @@
+import type { RootState } from '.'
+import type { UserInfoModel } from '@/model/system'
+import type { PayloadAction } from '@reduxjs/toolkit'
+
+import { createSlice } from '@reduxjs/toolkit'
+import { useDispatch, useSelector } from 'react-redux'
+
+import { fetchUserInfo } from '@/api/system'
+import { TOKEN, USER_INFO } from '@/utils/storage'
+import storage from '@/utils/storage'
+
+export interface UserInfoStore {
+  clear: () => void
+  refreshUserInfo: () => void
+  setUserInfo: (token: string, data: UserInfoModel) => void
+  token?: string
+  userInfo?: UserInfoModel
+}
+
+type UserInfoState = Pick<UserInfoStore, 'token' | 'userInfo'>
+
+const initialState: UserInfoState = {
+  token: storage.get(TOKEN) as string,
+  userInfo: undefined,
+}
+
+export const userSlice = createSlice({
+  initialState,
+  name: 'user',
+  reducers: {
+    setState: (state, action: PayloadAction<Partial<UserInfoState>>) => ({
+      ...state,
+      ...action.payload,
+    }),
+  },
+})
+
+/**
+ * 获取用户信息状态，用法与zustand创建的hook一致
+ * @param selector 从状态中选取需要的值
+ */
+const userInfoStore = <T>(selector: (state: UserInfoStore) => T): T => {
+  const dispatch = useDispatch()
+  const state = useSelector((state: RootState) => state.user)
+  const set = (partial: Partial<UserInfoState>) => dispatch(userSlice.actions.setState(partial))
+
+  return selector({
+    ...state,
+    clear: () => {
+      storage.remove(USER_INFO)
+      storage.remove(TOKEN)
+      set({ token: undefined, userInfo: undefined })
+    },
+    refreshUserInfo: async () => {
+      const { data } = await fetchUserInfo()
+      storage.set(USER_INFO, data)
+      set({ userInfo: data })
+    },
+    setUserInfo: (token: string, data: UserInfoModel) => {
+      storage.set(TOKEN, token)
+      storage.set(USER_INFO, data)
+      set({ token, userInfo: data })
+    },
+  })
+}
+
+export default userInfoStore
@@ -1,7 +0,0 @@
-export { default as AntdConfigProvider } from './AntdConfigProvider'
-export { default as AuthRoute } from './AuthRoute'
-export { default as AutoFirstPath } from './AutoFirstPath'
-export { default as CenterSpin } from './CenterSpin'
-export { default as DynamicIcon } from './DynamicIcon'
-export { default as LazyLoadSpin } from './LazyLoadSpin'
-export { default as ProBreadcrumb } from './ProBreadcrumb'
@@ -1,7 +0,0 @@
-import React from 'react'
-
-const Password = () => {
-  return <div>Password</div>
-}
-
-export default Password
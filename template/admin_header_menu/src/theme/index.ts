@@ -1,10 +0,0 @@
-// 变量地址参考: https://ant-design.antgroup.com/docs/react/customize-theme-cn#theme
-export default {
-  colorError: '#ff4d4f',
-  colorPrimary: '#4170ff',
-  colorSuccess: '#00c48c',
-  colorText: '#3c4761',
-  colorTextDisabled: '#c5cee0',
-  colorTextSecondary: '#7487a3',
-  colorWarning: '#ffc245',
-}
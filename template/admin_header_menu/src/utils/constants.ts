@@ -1,9 +0,0 @@
-// 按钮Key
-export enum BUTTON_KEY {
-  // 添加
-  ADD = 'ADD',
-  // 编辑
-  EDIT = 'EDIT',
-  // 导出
-  EXPORT = 'EXPORT',
-}
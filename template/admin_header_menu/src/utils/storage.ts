@@ -1,6 +0,0 @@
-const prefix = 'RZPACK'
-export const TOKEN = `${prefix}_TOKEN`
-export const USER_INFO = `${prefix}_USER_INFO`
-export const REMEMBER_INFO = `${prefix}_REMEMBER_INFO`
-import storage from '@renzp/storage'
-export default storage
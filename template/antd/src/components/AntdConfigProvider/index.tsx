@@ -1,12 +1,21 @@
-import { ConfigProvider } from 'antd'
+import { ConfigProvider, theme } from 'antd'
 import zhCN from 'antd/es/locale/zh_CN'
 import dayjs from 'dayjs'
 import React from 'react'
 
+import token, { algorithms } from '@/theme'
+
 import 'dayjs/locale/zh-cn'
 
 dayjs.locale('zh-cn')
 
+// 将主题配置中的算法名称转换为antd的算法
+const algorithmMap = {
+  compact: theme.compactAlgorithm,
+  dark: theme.darkAlgorithm,
+  default: theme.defaultAlgorithm,
+}
+
 export interface AntdConfigProvider {
   children: React.ReactNode
 }
@@ -18,6 +27,7 @@ const AntdConfigProvider = (props: AntdConfigProvider) => {
       autoInsertSpaceInButton={false}
       // 中文配置
       locale={zhCN}
+      theme={{ algorithm: algorithms.map(item => algorithmMap[item]), token }}
     >
       {props.children}
     </ConfigProvider>
@@ -0,0 +1,44 @@
+import { App, ConfigProvider, theme } from 'antd'
+import zhCN from 'antd/es/locale/zh_CN'
+import { omit } from 'lodash-es'
+import React from 'react'
+
+import useThemeMode from '@/stores/theme'
+import token, { algorithms, type ThemeAlgorithm } from '@/theme'
+
+// 将主题配置中的算法名称转换为antd的算法
+const algorithmMap = {
+  compact: theme.compactAlgorithm,
+  dark: theme.darkAlgorithm,
+  default: theme.defaultAlgorithm,
+}
+
+export interface AntdConfigProvider {
+  children: React.ReactNode
+}
+
+const AntdConfigProvider = (props: AntdConfigProvider) => {
+  const mode = useThemeMode()
+  // 暗色算法由顶部的切换按钮控制，其他算法使用主题配置
+  const names: ThemeAlgorithm[] = algorithms.filter(item => item !== 'dark')
+  if (mode === 'dark') {
+    names.push('dark')
+  }
+  // 暗色模式下文字颜色由暗色算法生成
+  const themeToken =
+    mode === 'dark' ? omit(token, ['colorText', 'colorTextDisabled', 'colorTextSecondary']) : token
+
+  return (
+    <ConfigProvider
+      // 移除按钮汉字之间的空格
+      autoInsertSpaceInButton={false}
+      // 中文配置
+      locale={zhCN}
+      theme={{ algorithm: names.map(item => algorithmMap[item]), token: themeToken }}
+    >
+      <App>{props.children}</App>
+    </ConfigProvider>
+  )
+}
+
+export default AntdConfigProvider
@@ -1,4 +1,4 @@
-import { Dropdown } from 'antd'
+import { Dropdown, Space, Switch } from 'antd'
 import React from 'react'
 import { useNavigate } from 'react-router-dom'
 
@@ -6,6 +6,7 @@ import { modal } from '@/App'
 import { logout } from '@/api/system'
 import DownIcon from '@/assets/svg/down.svg'
 import LogoutIcon from '@/assets/svg/logout.svg'
+import useThemeMode, { toggleThemeMode } from '@/stores/theme'
 import userInfoStore from '@/stores/user'
 import storage, { TOKEN } from '@/utils/storage'
 
@@ -14,6 +15,7 @@ import classes from './index.module.less'
 const PageHeaderTools = () => {
   const navigate = useNavigate()
   const [userInfo, clearUserInfo] = userInfoStore(state => [state.userInfo, state.clear])
+  const themeMode = useThemeMode()
 
   const onLogout = () => {
     modal.confirm({
@@ -43,19 +45,27 @@ const PageHeaderTools = () => {
   ]
 
   return (
-    <Dropdown
-      menu={{
-        items: dropdownMenus,
-        onClick: onMenuClick,
-      }}
-      placement="bottomRight"
-      trigger={['click']}
-    >
-      <div className={classes.username}>
-        <div>{userInfo?.nickname}</div>
-        <DownIcon fill="#fff" height="16" width="16" />
-      </div>
-    </Dropdown>
+    <Space size={16} style={{ flex: 'none' }}>
+      <Switch
+        checked={themeMode === 'dark'}
+        checkedChildren="暗"
+        onChange={toggleThemeMode}
+        unCheckedChildren="亮"
+      />
+      <Dropdown
+        menu={{
+          items: dropdownMenus,
+          onClick: onMenuClick,
+        }}
+        placement="bottomRight"
+        trigger={['click']}
+      >
+        <div className={classes.username}>
+          <div>{userInfo?.nickname}</div>
+          <DownIcon fill="#fff" height="16" width="16" />
+        </div>
+      </Dropdown>
+    </Space>
   )
 }
 
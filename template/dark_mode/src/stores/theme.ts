@@ -0,0 +1,49 @@
+import { useSyncExternalStore } from 'react'
+
+import { algorithms } from '@/theme'
+import storage from '@/utils/storage'
+
+import '@/theme/variables.less'
+
+export type ThemeMode = 'dark' | 'light'
+
+const THEME_MODE = 'RZPACK_THEME_MODE'
+const listeners = new Set<() => void>()
+// 没有切换过时使用主题配置中的算法
+let mode: ThemeMode =
+  (storage.get(THEME_MODE) as ThemeMode) ?? (algorithms.includes('dark') ? 'dark' : 'light')
+
+// 通过data-theme切换variables.less中的css变量
+const applyThemeMode = () => {
+  document.documentElement.dataset.theme = mode
+}
+applyThemeMode()
+
+const subscribe = (listener: () => void) => {
+  listeners.add(listener)
+  return () => listeners.delete(listener)
+}
+
+/**
+ * 设置主题模式，设置后会持久化
+ * @param value 主题模式
+ */
+export const setThemeMode = (value: ThemeMode) => {
+  mode = value
+  storage.set(THEME_MODE, value)
+  applyThemeMode()
+  listeners.forEach(listener => listener())
+}
+
+/**
+ * 切换亮色/暗色模式
+ */
+export const toggleThemeMode = () => setThemeMode(mode === 'dark' ? 'light' : 'dark')
+
+/**
+ * 获取当前主题模式，主题状态不依赖选择的状态管理库
+ * @returns 当前主题模式
+ */
+const useThemeMode = () => useSyncExternalStore(subscribe, () => mode)
+
+export default useThemeMode
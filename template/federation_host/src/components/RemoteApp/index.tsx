@@ -0,0 +1,52 @@
+import { Button, Result } from 'antd'
+import React, { Component, Suspense } from 'react'
+
+import CenterSpin from '../CenterSpin'
+
+interface RemoteAppProps {
+  /** 子应用的名称，与src/remotes.ts中的name对应 */
+  name: string
+  /** 通过React.lazy引入的子应用模块，如：lazy(() => import('sub_app/App')) */
+  component: React.LazyExoticComponent<React.ComponentType>
+}
+
+interface RemoteAppState {
+  error: Error | null
+}
+
+// 子应用未启动或加载失败时展示错误信息，不影响主应用的其他页面
+class RemoteApp extends Component<RemoteAppProps, RemoteAppState> {
+  state: RemoteAppState = { error: null }
+
+  static getDerivedStateFromError(error: Error) {
+    return { error }
+  }
+
+  render() {
+    const { name, component: RemoteComponent } = this.props
+    const { error } = this.state
+
+    if (error) {
+      return (
+        <Result
+          extra={
+            <Button onClick={() => this.setState({ error: null })} type="primary">
+              重新加载
+            </Button>
+          }
+          status="error"
+          subTitle={error.message}
+          title={`子应用${name}加载失败`}
+        />
+      )
+    }
+
+    return (
+      <Suspense fallback={<CenterSpin />}>
+        <RemoteComponent />
+      </Suspense>
+    )
+  }
+}
+
+export default RemoteApp
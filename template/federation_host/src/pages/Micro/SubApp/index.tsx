@@ -0,0 +1,11 @@
+import React, { lazy } from 'react'
+
+import RemoteApp from '@/components/RemoteApp'
+
+const App = lazy(() => import('sub_app/App'))
+
+const SubApp = () => {
+  return <RemoteApp component={App} name="sub_app" />
+}
+
+export default SubApp
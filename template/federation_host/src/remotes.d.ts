@@ -0,0 +1,7 @@
+// 子应用暴露的模块的类型声明，新增子应用时需要同步添加
+declare module 'sub_app/App' {
+  import type React from 'react'
+
+  const App: React.ComponentType
+  export default App
+}
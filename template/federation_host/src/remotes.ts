@@ -0,0 +1,19 @@
+// 子应用注册表，rzpack.config.ts会根据这里生成moduleFederation的remotes
+export interface RemoteModel {
+  /** 子应用moduleFederation配置中的name，同时是引入模块时的前缀，如：sub_app/App */
+  name: string
+  /** 子应用remoteEntry.js的地址 */
+  entry: string
+}
+
+const remotes: RemoteModel[] = [
+  {
+    name: 'sub_app',
+    entry: 'http://localhost:8081/remoteEntry.js',
+  },
+]
+
+export const getFederationRemotes = () =>
+  Object.fromEntries(remotes.map(item => [item.name, `${item.name}@${item.entry}`]))
+
+export default remotes
@@ -0,0 +1,2 @@
+// 异步加载入口，保证共享依赖(react/antd)在使用前完成版本协商
+import('./bootstrap')
@@ -0,0 +1 @@
+module.exports = 'test-file-stub'
@@ -0,0 +1,16 @@
+import '@testing-library/jest-dom'
+
+// antd的响应式组件依赖matchMedia，jsdom中没有实现
+Object.defineProperty(window, 'matchMedia', {
+  value: (query: string) => ({
+    addEventListener: () => {},
+    addListener: () => {},
+    dispatchEvent: () => false,
+    matches: false,
+    media: query,
+    onchange: null,
+    removeEventListener: () => {},
+    removeListener: () => {},
+  }),
+  writable: true,
+})
@@ -0,0 +1,22 @@
+import { Space } from 'antd'
+import React from 'react'
+
+import { StatusTag } from '.'
+
+// 组件的演示页面，通过rzpack启动，不会被打包到组件库中
+const App = () => {
+  return (
+    <div style={{ padding: 24 }}>
+      <h2>StatusTag</h2>
+      <Space>
+        <StatusTag>默认</StatusTag>
+        <StatusTag status="success">成功</StatusTag>
+        <StatusTag status="processing">进行中</StatusTag>
+        <StatusTag status="warning">警告</StatusTag>
+        <StatusTag status="error">失败</StatusTag>
+      </Space>
+    </div>
+  )
+}
+
+export default App
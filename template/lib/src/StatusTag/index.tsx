@@ -0,0 +1,16 @@
+import { Tag } from 'antd'
+import React from 'react'
+
+export type Status = 'default' | 'error' | 'processing' | 'success' | 'warning'
+
+export interface StatusTagProps {
+  /** 状态，决定标签的颜色 */
+  status?: Status
+  children?: React.ReactNode
+}
+
+const StatusTag = ({ status = 'default', children }: StatusTagProps) => {
+  return <Tag color={status}>{children}</Tag>
+}
+
+export default StatusTag
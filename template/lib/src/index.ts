@@ -0,0 +1,3 @@
+// 组件库的入口，只有从这里导出的组件会被打包发布
+export { default as StatusTag } from './StatusTag'
+export type { StatusTagProps, Status } from './StatusTag'
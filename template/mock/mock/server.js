@@ -0,0 +1,104 @@
+/* eslint-disable @typescript-eslint/no-var-requires */
+// 开发环境使用的Mock接口服务，只依赖node内置模块和typescript，通过`npm run mock`单独启动
+const fs = require('fs')
+const http = require('http')
+const path = require('path')
+
+const PORT = process.env.MOCK_PORT || 3001
+// 接口延迟(毫秒)，用于模拟网络请求
+const DELAY = 300
+const API_DIR = path.resolve(__dirname, '../src/api')
+// 请求函数中的接口，如request.post('/v1/system/login')、request.get(`/v1/user/${id}`)
+const REQUEST_PATTERN = /request\.(get|post|put|patch|delete)(?:<[^>]*>)?\(\s*(['`])([^'`]+)\2/g
+
+/**
+ * mock目录下其他文件中的Mock数据，以接口地址为key
+ * 修改后需要重启Mock接口服务
+ */
+const mocks = Object.assign(
+  {},
+  ...fs
+    .readdirSync(__dirname)
+    .filter(file => file.endsWith('.js') && file !== path.basename(__filename))
+    .map(file => require(path.join(__dirname, file)))
+)
+
+// 接口地址转为正则，路径参数匹配任意一段路径，地址可能带有基础路径(如/api)，按后缀匹配
+const toPattern = url =>
+  new RegExp(
+    `${url
+      .split(/\$\{[^}]+\}/)
+      .map(part => part.replace(/[.*+?^$()|[\]\\]/g, '\\$&'))
+      .join('[^/]+')}$`
+  )
+
+/**
+ * 根据src/api下的请求函数生成Mock接口，每次请求时读取，新增的接口不需要重启
+ * 没有Mock数据的接口返回空数据
+ */
+const getHandlers = () =>
+  fs
+    .readdirSync(API_DIR)
+    .filter(file => file.endsWith('.ts') && file !== 'request.ts')
+    .flatMap(file => [
+      ...fs.readFileSync(path.join(API_DIR, file), 'utf-8').matchAll(REQUEST_PATTERN),
+    ])
+    .map(([, method, , url]) => ({
+      handle:
+        mocks[url] ??
+        (() => {
+          console.warn(`接口${url}没有Mock数据，返回空数据，可以在mock目录下添加`)
+          return { code: 0, data: null, msg: 'success' }
+        }),
+      method: method.toUpperCase(),
+      pattern: toPattern(url),
+    }))
+
+const send = (res, status, body) => {
+  res.writeHead(status, {
+    'Access-Control-Allow-Headers': 'Authorization, Content-Type, _showErrorMessage',
+    'Access-Control-Allow-Methods': 'GET, POST, PUT, PATCH, DELETE, OPTIONS',
+    'Access-Control-Allow-Origin': '*',
+    'Content-Type': 'application/json; charset=utf-8',
+  })
+  res.end(body === undefined ? '' : JSON.stringify(body))
+}
+
+const readBody = req =>
+  new Promise(resolve => {
+    let data = ''
+    req.on('data', chunk => (data += chunk))
+    req.on('end', () => {
+      try {
+        resolve(data ? JSON.parse(data) : {})
+      } catch {
+        resolve({})
+      }
+    })
+  })
+
+const server = http.createServer(async (req, res) => {
+  if (req.method === 'OPTIONS') {
+    return send(res, 204)
+  }
+
+  const url = new URL(req.url, `http://${req.headers.host}`)
+  const handler = getHandlers().find(
+    item => item.method === req.method && item.pattern.test(url.pathname)
+  )
+  if (!handler) {
+    return send(res, 404, { code: 404, data: null, msg: `未找到接口：${url.pathname}` })
+  }
+
+  const body = await readBody(req)
+  const result = handler.handle({
+    body,
+    headers: req.headers,
+    query: Object.fromEntries(url.searchParams),
+  })
+  setTimeout(() => send(res, 200, result), DELAY)
+})
+
+server.listen(PORT, () => {
+  console.log(`Mock接口服务已启动：http://localhost:${PORT}`)
+})
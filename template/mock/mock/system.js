@@ -0,0 +1,94 @@
+/* eslint-disable @typescript-eslint/no-var-requires */
+// src/api/system.ts中接口的Mock数据
+const fs = require('fs')
+const path = require('path')
+const ts = require('typescript')
+const vm = require('vm')
+
+const ALL_PERMISSION = '*'
+
+// 登录账号对应的用户，使用guest登录可以查看没有权限的菜单被过滤后的效果
+const users = {
+  guest: {
+    nickname: '访客',
+    permissions: [],
+    phone: '13800000001',
+    roleList: [{ id: '2', permissions: [], roleDesc: '只能访问不限制权限的页面', roleName: '访客' }],
+    userEmail: 'guest@example.com',
+    userId: '2',
+    username: 'guest',
+  },
+  test001: {
+    nickname: '测试001',
+    // 开启权限控制时使用，*表示拥有所有权限
+    permissions: [ALL_PERMISSION],
+    phone: '13800000000',
+    roleList: [
+      { id: '1', permissions: [ALL_PERMISSION], roleDesc: '拥有所有权限', roleName: '超级管理员' },
+    ],
+    userEmail: 'test001@example.com',
+    userId: '1',
+    username: 'test001',
+  },
+}
+// 其他账号登录时使用test001的信息
+const getUser = username => users[username] ?? { ...users.test001, username }
+const getToken = username => `MOCK_TOKEN_${username}`
+
+const success = data => ({ code: 0, data, msg: 'success' })
+const unauthorized = () => ({ code: 401, data: null, msg: '登录已过期，请重新登录' })
+
+/**
+ * 读取src/router/routes.ts作为菜单数据，新增的页面会自动出现在菜单中
+ * 通过typescript去掉类型后执行，routes.ts中引用的其他模块为空对象
+ */
+const getRoutes = () => {
+  const source = fs.readFileSync(path.resolve(__dirname, '../src/router/routes.ts'), 'utf-8')
+  const { outputText } = ts.transpileModule(source, {
+    compilerOptions: { module: ts.ModuleKind.CommonJS, target: ts.ScriptTarget.ES2020 },
+  })
+  const module = { exports: {} }
+  vm.runInNewContext(outputText, { exports: module.exports, module, require: () => ({}) })
+
+  return module.exports.default
+}
+
+// 拥有任意一个需要的权限即可访问，未配置权限时不限制
+const hasPermission = (granted, required = []) =>
+  !required.length || granted.includes(ALL_PERMISSION) || required.some(item => granted.includes(item))
+
+// 递归过滤掉用户没有权限的菜单
+const filterRoutes = (routes, granted) =>
+  routes
+    .filter(item => hasPermission(granted, item.permissions))
+    .map(item => ({
+      ...item,
+      children: item.children ? filterRoutes(item.children, granted) : undefined,
+    }))
+
+// 除登录外的接口都需要登录，根据token获取当前登录的用户
+const withAuth =
+  handler =>
+  ({ headers, ...rest }) => {
+    const username = headers.authorization?.replace(/^MOCK_TOKEN_/, '')
+    if (!username || headers.authorization !== getToken(username)) {
+      return unauthorized()
+    }
+
+    return handler({ headers, user: getUser(username), ...rest })
+  }
+
+module.exports = {
+  '/v1/system/listMenuButtonByUser': withAuth(({ user }) =>
+    success({ buttonKeys: {}, menuTree: filterRoutes(getRoutes(), user.permissions) })
+  ),
+  '/v1/system/login': ({ body }) => {
+    if (!body.username || !body.password) {
+      return { code: 1, data: null, msg: '账号或密码不能为空' }
+    }
+
+    return success({ token: getToken(body.username), userInfo: getUser(body.username) })
+  },
+  '/v1/system/logout': withAuth(() => success(null)),
+  '/v1/system/userinfo': withAuth(({ user }) => success(user)),
+}
@@ -0,0 +1,40 @@
+import type { UserInfoModel } from '@/model/system'
+import type { RouteModel } from '@/router'
+import type { Response } from './request'
+
+import request from './request'
+
+export interface LoginParams {
+  // 密码
+  password: string
+  // 账号
+  username: string
+}
+export interface LoginResult {
+  token: string
+  userInfo: UserInfoModel
+}
+/**
+ * 登录
+ * @param params LoginParams
+ * @returns 返回token和用户信息
+ */
+export const login = (params: LoginParams): Response<LoginResult> =>
+  request.post('/v1/system/login', params)
+/**
+ * 获取当前登录用户权限
+ * @returns 返回菜单及按钮权限
+ */
+export const fetchAuths = (): Response<{
+  buttonKeys: Record<string, string[]>
+  menuTree: RouteModel[]
+}> => request.post('/v1/system/listMenuButtonByUser')
+/**
+ * 退出登录
+ */
+export const logout = (): Response<unknown> => request.post('/v1/system/logout')
+/**
+ * 获取当前登录用户信息
+ * @returns 返回用户信息
+ */
+export const fetchUserInfo = (): Response<UserInfoModel> => request.post('/v1/system/userinfo')
@@ -0,0 +1,13 @@
+module.exports = {
+  extends: ['stylelint-config-standard', 'stylelint-config-property-sort-order-smacss'],
+  plugins: ['stylelint-order'],
+  rules: {
+    'selector-pseudo-class-no-unknown': null,
+  },
+  overrides: [
+    {
+      files: ['**/*.less'],
+      customSyntax: 'postcss-less',
+    },
+  ],
+}
@@ -0,0 +1,32 @@
+import React from 'react'
+
+export interface PageContainerProps {
+  /** 页面标题 */
+  title?: React.ReactNode
+  /** 标题右侧的操作区域 */
+  extra?: React.ReactNode
+  children?: React.ReactNode
+}
+
+const PageContainer = ({ title, extra, children }: PageContainerProps) => {
+  return (
+    <section style={{ padding: 24 }}>
+      {title || extra ? (
+        <header
+          style={{
+            alignItems: 'center',
+            display: 'flex',
+            justifyContent: 'space-between',
+            marginBottom: 16,
+          }}
+        >
+          <h2 style={{ fontSize: 20, margin: 0 }}>{title}</h2>
+          {extra}
+        </header>
+      ) : null}
+      {children}
+    </section>
+  )
+}
+
+export default PageContainer
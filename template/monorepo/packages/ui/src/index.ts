@@ -0,0 +1,2 @@
+export { default as PageContainer } from './PageContainer'
+export type { PageContainerProps } from './PageContainer'
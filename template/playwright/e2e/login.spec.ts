@@ -0,0 +1,37 @@
+import { expect, test } from '@playwright/test'
+
+import { matchURL, toURL } from './utils'
+
+test.describe('登录页', () => {
+  test.beforeEach(async ({ page }) => {
+    await page.goto(toURL('/login'))
+  })
+
+  test('未填写账号密码时提示必填', async ({ page }) => {
+    await page.getByRole('button', { exact: true, name: '登录' }).click()
+
+    await expect(page.getByText('请输入账号', { exact: true })).toBeVisible()
+    await expect(page.getByText('请输入密码', { exact: true })).toBeVisible()
+  })
+
+  test('登录成功后离开登录页', async ({ page }) => {
+    await page.getByLabel('账号', { exact: true }).fill('admin')
+    await page.getByLabel('密码', { exact: true }).fill('123456')
+    await page.getByRole('button', { exact: true, name: '登录' }).click()
+
+    await expect(page.getByText('登录成功')).toBeVisible()
+    await expect(page).not.toHaveURL(matchURL('/login'))
+  })
+
+  test('勾选记住密码后回填账号', async ({ page }) => {
+    await page.getByLabel('账号', { exact: true }).fill('admin')
+    await page.getByLabel('密码', { exact: true }).fill('123456')
+    await page.getByLabel('记住密码').check()
+    await page.getByRole('button', { exact: true, name: '登录' }).click()
+    await expect(page.getByText('登录成功')).toBeVisible()
+
+    await page.goto(toURL('/login'))
+
+    await expect(page.getByLabel('账号', { exact: true })).toHaveValue('admin')
+  })
+})
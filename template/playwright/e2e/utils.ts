@@ -0,0 +1,15 @@
+// 路由地址的前缀，需要与admin模板选择的路由模式一致，hash模式为/#，history模式为空
+export const ROUTE_PREFIX = '/#'
+
+/**
+ * 根据路由模式生成页面地址
+ * @param path 路由地址，如/login
+ * @returns 页面地址，如hash模式下为/#/login
+ */
+export const toURL = (path: string) => `${ROUTE_PREFIX}${path}`
+
+/**
+ * 匹配以路由地址结尾的页面地址，用于toHaveURL断言
+ * @param path 路由地址，如/login
+ */
+export const matchURL = (path: string) => new RegExp(`${toURL(path)}$`)
@@ -0,0 +1,27 @@
+import { defineConfig, devices } from '@playwright/test'
+
+// 需要与开发服务器的地址保持一致
+const baseURL = 'http://localhost:3000'
+
+export default defineConfig({
+  forbidOnly: !!process.env.CI,
+  fullyParallel: true,
+  projects: [
+    {
+      name: 'chromium',
+      use: { ...devices['Desktop Chrome'] },
+    },
+  ],
+  reporter: 'html',
+  retries: process.env.CI ? 2 : 0,
+  testDir: './e2e',
+  use: {
+    baseURL,
+    trace: 'on-first-retry',
+  },
+  webServer: {
+    command: 'npm run dev',
+    reuseExistingServer: !process.env.CI,
+    url: baseURL,
+  },
+})
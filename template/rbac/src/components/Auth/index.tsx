@@ -0,0 +1,22 @@
+import React from 'react'
+
+import usePermission from '@/utils/usePermission'
+
+export interface AuthProps {
+  children: React.ReactNode
+  // 没有权限时显示的内容
+  fallback?: React.ReactNode
+  // 需要的权限，数组时拥有其中任意一个即可
+  permission: string | string[]
+}
+
+/**
+ * 按钮级别的权限控制，没有权限时不渲染子元素
+ */
+const Auth = (props: AuthProps) => {
+  const [hasPermission] = usePermission()
+
+  return <>{hasPermission(props.permission) ? props.children : props.fallback ?? null}</>
+}
+
+export default Auth
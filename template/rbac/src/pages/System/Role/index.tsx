@@ -0,0 +1,135 @@
+import type { RoleModel } from '@/model/system'
+import type { RouteModel } from '@/router'
+import type { ColumnsType } from 'antd/es/table'
+
+import { Button, Form, Input, Modal, Select, Space, Table, Tag } from 'antd'
+import React from 'react'
+import { useState } from 'react'
+
+import { modal } from '@/App'
+import { Auth } from '@/components'
+import { routes } from '@/router'
+import { ALL_PERMISSION } from '@/utils/permission'
+import useModal from '@/utils/useModal'
+
+// 角色管理的权限
+export const ROLE_PERMISSION = {
+  ADD: 'system:role:add',
+  DELETE: 'system:role:delete',
+  EDIT: 'system:role:edit',
+}
+
+// 实际开发时，将角色数据改为通过接口获取和保存
+const defaultRoles: RoleModel[] = [
+  { id: '1', permissions: [ALL_PERMISSION], roleDesc: '拥有所有权限', roleName: '超级管理员' },
+  {
+    id: '2',
+    permissions: ['system:role', ROLE_PERMISSION.ADD, ROLE_PERMISSION.EDIT],
+    roleDesc: '可以查看和编辑角色',
+    roleName: '管理员',
+  },
+]
+
+// 可分配的权限：路由上配置的权限及页面中的按钮权限
+const getPermissionOptions = (items: RouteModel[]): { label: string; value: string }[] =>
+  items.flatMap(item => [
+    ...(item.permissions ?? []).map(value => ({ label: `${item.title}(${value})`, value })),
+    ...getPermissionOptions(item.children ?? []),
+  ])
+const permissionOptions = [
+  { label: '所有权限', value: ALL_PERMISSION },
+  ...getPermissionOptions(routes),
+  ...Object.values(ROLE_PERMISSION).map(value => ({ label: value, value })),
+]
+
+const Role = () => {
+  const [roles, setRoles] = useState(defaultRoles)
+  const [form] = Form.useForm<RoleModel>()
+  const { hideModal, modalData, modalProps, setModalData, setTitle, showModal } =
+    useModal<RoleModel>()
+
+  const onEdit = (record?: RoleModel) => {
+    setTitle(record ? '编辑角色' : '新增角色')
+    setModalData(record)
+    form.setFieldsValue(record ?? { permissions: [], roleDesc: '', roleName: '' })
+    showModal()
+  }
+
+  const onDelete = (record: RoleModel) => {
+    modal.confirm({
+      content: `确定删除角色${record.roleName}吗?`,
+      onOk: () => setRoles(roles.filter(item => item.id !== record.id)),
+      title: '提示',
+    })
+  }
+
+  const onOk = async () => {
+    const values = await form.validateFields()
+    if (modalData) {
+      setRoles(roles.map(item => (item.id === modalData.id ? { ...item, ...values } : item)))
+    } else {
+      setRoles([...roles, { ...values, id: `${Date.now()}` }])
+    }
+    hideModal()
+  }
+
+  const columns: ColumnsType<RoleModel> = [
+    { dataIndex: 'roleName', title: '角色名' },
+    { dataIndex: 'roleDesc', title: '角色描述' },
+    {
+      dataIndex: 'permissions',
+      render: (permissions: string[]) => permissions.map(item => <Tag key={item}>{item}</Tag>),
+      title: '权限',
+    },
+    {
+      key: 'action',
+      render: (_, record) => (
+        <Space>
+          <Auth permission={ROLE_PERMISSION.EDIT}>
+            <Button onClick={() => onEdit(record)} type="link">
+              编辑
+            </Button>
+          </Auth>
+          <Auth permission={ROLE_PERMISSION.DELETE}>
+            <Button danger onClick={() => onDelete(record)} type="link">
+              删除
+            </Button>
+          </Auth>
+        </Space>
+      ),
+      title: '操作',
+    },
+  ]
+
+  return (
+    <>
+      <Space direction="vertical" style={{ width: '100%' }}>
+        <Auth permission={ROLE_PERMISSION.ADD}>
+          <Button onClick={() => onEdit()} type="primary">
+            新增角色
+          </Button>
+        </Auth>
+        <Table columns={columns} dataSource={roles} rowKey="id" />
+      </Space>
+      <Modal {...modalProps} onOk={onOk}>
+        <Form form={form} labelCol={{ span: 4 }}>
+          <Form.Item
+            label="角色名"
+            name="roleName"
+            rules={[{ message: '请输入角色名', required: true }]}
+          >
+            <Input placeholder="请输入角色名" />
+          </Form.Item>
+          <Form.Item label="角色描述" name="roleDesc">
+            <Input.TextArea placeholder="请输入角色描述" />
+          </Form.Item>
+          <Form.Item label="权限" name="permissions">
+            <Select mode="multiple" options={permissionOptions} placeholder="请选择权限" />
+          </Form.Item>
+        </Form>
+      </Modal>
+    </>
+  )
+}
+
+export default Role
@@ -0,0 +1,43 @@
+import type { UserInfoModel } from '@/model/system'
+import type { RouteModel } from '@/router'
+
+import storage, { USER_INFO } from '@/utils/storage'
+
+// 拥有所有权限
+export const ALL_PERMISSION = '*'
+
+/**
+ * 判断是否拥有权限
+ * @param granted 拥有的权限
+ * @param required 需要的权限，数组时拥有其中任意一个即可，为空时不限制
+ * @returns 有权限返回true,否则返回false
+ */
+export const hasPermission = (granted: string[] = [], required?: string | string[]): boolean => {
+  const permissions = typeof required === 'string' ? [required] : required ?? []
+  if (!permissions.length || granted.includes(ALL_PERMISSION)) {
+    return true
+  }
+
+  return permissions.some(item => granted.includes(item))
+}
+
+/**
+ * 获取缓存的当前登录用户的权限，登录后会缓存用户信息
+ * @returns 拥有的权限
+ */
+export const getCachedPermissions = (): string[] =>
+  (storage.get(USER_INFO) as UserInfoModel | undefined)?.permissions ?? []
+
+/**
+ * 递归过滤掉没有权限的路由
+ * @param routes 路由数据
+ * @param granted 拥有的权限
+ * @returns 有权限的路由
+ */
+export const filterRoutesByPermission = (routes: RouteModel[], granted: string[]): RouteModel[] =>
+  routes
+    .filter(item => hasPermission(granted, item.permissions))
+    .map(item => ({
+      ...item,
+      children: item.children ? filterRoutesByPermission(item.children, granted) : undefined,
+    }))
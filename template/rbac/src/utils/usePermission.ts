@@ -0,0 +1,17 @@
+import userInfoStore from '@/stores/user'
+
+import { getCachedPermissions, hasPermission } from './permission'
+
+/**
+ * 获取当前登录用户的权限
+ * @returns 返回一个数组，数组第一个元素是判断是否有权限的函数，第二个元素是拥有的权限数组
+ */
+const usePermission = (): [(required?: string | string[]) => boolean, string[]] => {
+  const userInfo = userInfoStore(state => state.userInfo)
+  // 刷新页面时用户信息还未请求回来，使用缓存的用户信息
+  const permissions = userInfo?.permissions ?? getCachedPermissions()
+
+  return [(required?: string | string[]) => hasPermission(permissions, required), permissions]
+}
+
+export default usePermission
@@ -0,0 +1,18 @@
+import { useQuery } from '@tanstack/react-query'
+
+import { fetchUserInfo } from './system'
+
+// 查询的key，统一维护便于在修改数据后使缓存失效
+export const queryKeys = {
+  userInfo: ['system', 'userInfo'] as const,
+}
+
+/**
+ * 获取当前登录用户信息
+ * @returns 返回useQuery的结果，data为用户信息
+ */
+export const useUserInfoQuery = () =>
+  useQuery({
+    queryFn: async () => (await fetchUserInfo()).data,
+    queryKey: queryKeys.userInfo,
+  })
@@ -0,0 +1,13 @@
+import { QueryClient } from '@tanstack/react-query'
+
+const queryClient = new QueryClient({
+  defaultOptions: {
+    queries: {
+      refetchOnWindowFocus: false,
+      // 错误提示及401处理已在请求层中完成，失败后不再重试
+      retry: false,
+    },
+  },
+})
+
+export default queryClient
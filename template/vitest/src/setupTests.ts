@@ -0,0 +1,21 @@
+import { cleanup } from '@testing-library/react'
+import { afterEach } from 'vitest'
+
+import '@testing-library/jest-dom/vitest'
+
+afterEach(() => cleanup())
+
+// antd的响应式组件依赖matchMedia，jsdom中没有实现
+Object.defineProperty(window, 'matchMedia', {
+  value: (query: string) => ({
+    addEventListener: () => {},
+    addListener: () => {},
+    dispatchEvent: () => false,
+    matches: false,
+    media: query,
+    onchange: null,
+    removeEventListener: () => {},
+    removeListener: () => {},
+  }),
+  writable: true,
+})
@@ -0,0 +1,16 @@
+import { fileURLToPath } from 'url'
+import { configDefaults, defineConfig } from 'vitest/config'
+
+export default defineConfig({
+  resolve: {
+    alias: {
+      '@': fileURLToPath(new URL('./src', import.meta.url)),
+    },
+  },
+  test: {
+    environment: 'jsdom',
+    // e2e目录下是Playwright的用例
+    exclude: [...configDefaults.exclude, 'e2e/**'],
+    setupFiles: ['./src/setupTests.ts'],
+  },
+})